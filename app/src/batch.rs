@@ -0,0 +1,294 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+#[cfg(test)]
+mod tests;
+
+use clap::Args;
+use intel_crashlog::prelude::*;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Options controlling how several input files are processed
+#[derive(Args)]
+pub struct Options {
+    /// Directory where the output files are written (defaults to the directory of each input file)
+    #[arg(short, long, value_name = "dir")]
+    output_dir: Option<PathBuf>,
+    /// Name of the output files. `{stem}` and `{name}` are replaced by the stem and the name of
+    /// the input file.
+    #[arg(long, value_name = "template")]
+    output_name: Option<String>,
+    /// Number of files processed in parallel (defaults to the number of CPUs)
+    #[arg(short, long, value_name = "n")]
+    jobs: Option<NonZeroUsize>,
+}
+
+/// Result of the processing of an input file
+pub enum Status {
+    /// All the records have been decoded
+    Success,
+    /// Only the headers of the given number of records have been decoded
+    Partial(usize),
+}
+
+impl Status {
+    /// Counts the records of the `crashlogs` for which no decode definitions are available.
+    pub fn of<T: CollateralTree>(cm: &CollateralManager<T>, crashlogs: &[CrashLog]) -> Self {
+        let undecoded = crashlogs
+            .iter()
+            .flat_map(|crashlog| crashlog.regions.iter())
+            .flat_map(|region| region.records.iter())
+            .filter(|record| !record.has_decode_definitions(cm))
+            .count();
+
+        if undecoded > 0 {
+            Status::Partial(undecoded)
+        } else {
+            Status::Success
+        }
+    }
+}
+
+impl Options {
+    /// Checks whether the outputs must be written to files instead of the standard output.
+    pub fn writes_files(&self) -> bool {
+        self.output_dir.is_some() || self.output_name.is_some()
+    }
+
+    /// Expands the `inputs` and runs `process` on each resulting file in parallel, then prints
+    /// a summary.
+    ///
+    /// If `to_files` is set, the output of each input file is written to its own file named
+    /// after `default_name` unless overridden by the options. Otherwise, the outputs are printed
+    /// in the order of the input files.
+    ///
+    /// Returns [ExitCode::FAILURE] if any of the files could not be processed.
+    pub fn process(
+        &self,
+        inputs: &[PathBuf],
+        to_files: bool,
+        default_name: &str,
+        process: impl Fn(&Path, &mut dyn Write) -> Result<Status, Error> + Sync,
+    ) -> Result<ExitCode, Error> {
+        if let Some(dir) = self.output_dir.as_deref() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let inputs = expand(inputs);
+        let results = self.run(&inputs, |input| {
+            if !to_files {
+                let mut output = Vec::new();
+                return (process(input, &mut output), output);
+            }
+
+            let path = self.output_path(input, default_name);
+            let result = File::create(&path).map_err(Error::from).and_then(|file| {
+                let mut output = BufWriter::new(file);
+                let status = process(input, &mut output)?;
+                output.flush()?;
+                Ok(status)
+            });
+
+            if result.is_ok() {
+                println!("{}", path.display());
+            } else {
+                let _ = std::fs::remove_file(&path);
+            }
+            (result, Vec::new())
+        });
+
+        let mut stdout = std::io::stdout().lock();
+        for (input, (_, output)) in inputs.iter().zip(&results) {
+            if output.is_empty() {
+                continue;
+            }
+            if inputs.len() > 1 {
+                writeln!(stdout, "\n{}:\n", input.display())?;
+            }
+            stdout.write_all(output)?;
+        }
+
+        let results: Vec<_> = results.into_iter().map(|(result, _)| result).collect();
+        let summary = Summary::new(&results);
+        summary.print(&inputs, &results);
+        Ok(summary.exit_code())
+    }
+
+    /// Returns the path of the file where the output generated from `input` is written.
+    fn output_path(&self, input: &Path, default_name: &str) -> PathBuf {
+        let stem = input.file_stem().unwrap_or_default().to_string_lossy();
+        let name = input.file_name().unwrap_or_default().to_string_lossy();
+        let file_name = self
+            .output_name
+            .as_deref()
+            .unwrap_or(default_name)
+            .replace("{stem}", &stem)
+            .replace("{name}", &name);
+
+        match self.output_dir.as_deref() {
+            Some(dir) => dir.join(file_name),
+            None => input.with_file_name(file_name),
+        }
+    }
+
+    /// Runs `process` on each of the `inputs` in parallel and returns the results in the order
+    /// of the inputs.
+    fn run<R: Send>(&self, inputs: &[PathBuf], process: impl Fn(&Path) -> R + Sync) -> Vec<R> {
+        let jobs = self
+            .jobs
+            .or_else(|| std::thread::available_parallelism().ok())
+            .map_or(1, NonZeroUsize::get)
+            .min(inputs.len());
+        let next = AtomicUsize::new(0);
+
+        let mut results: Vec<(usize, R)> = std::thread::scope(|scope| {
+            let workers: Vec<_> = (0..jobs)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut results = Vec::new();
+                        loop {
+                            let i = next.fetch_add(1, Ordering::Relaxed);
+                            let Some(input) = inputs.get(i) else {
+                                break results;
+                            };
+                            results.push((i, process(input)));
+                        }
+                    })
+                })
+                .collect();
+
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().expect("Worker thread panicked"))
+                .collect()
+        });
+
+        results.sort_by_key(|(i, _)| *i);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+}
+
+/// Checks whether the input argument designates more than a single file.
+pub fn is_batch(inputs: &[PathBuf]) -> bool {
+    inputs.len() > 1
+        || inputs
+            .iter()
+            .any(|input| input.is_dir() || (!input.exists() && is_pattern(input)))
+}
+
+/// Checks whether the `input` contains any of the special characters of the glob patterns.
+fn is_pattern(input: &Path) -> bool {
+    input.to_string_lossy().contains(['*', '?', '['])
+}
+
+/// Expands the directories and the glob patterns found in the input arguments into the list of
+/// the files to process.
+///
+/// Directories are walked recursively. Inputs that do not match any file are kept as is, such
+/// that they are reported as failures.
+fn expand(inputs: &[PathBuf]) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for input in inputs {
+        if input.exists() {
+            walk(input, &mut files);
+            continue;
+        }
+
+        let matches: Vec<PathBuf> = glob::glob(&input.to_string_lossy())
+            .inspect_err(|err| log::error!("Invalid pattern: {err}"))
+            .map(|paths| paths.filter_map(Result::ok).collect())
+            .unwrap_or_default();
+
+        if matches.is_empty() {
+            files.push(input.clone());
+        }
+        for path in matches {
+            walk(&path, &mut files);
+        }
+    }
+    files
+}
+
+/// Lists the files located in the directory at `path` recursively, sorted by path. If `path` is
+/// a file, only the file itself is listed.
+pub fn walk(path: &Path, files: &mut Vec<PathBuf>) {
+    if !path.is_dir() {
+        files.push(path.to_path_buf());
+        return;
+    }
+
+    let mut entries: Vec<PathBuf> = match std::fs::read_dir(path) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .collect(),
+        Err(err) => {
+            log::error!("Cannot read directory {}: {err}", path.display());
+            return;
+        }
+    };
+    entries.sort();
+    for entry in entries {
+        walk(&entry, files);
+    }
+}
+
+/// Number of input files for each outcome of the processing
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Summary {
+    successes: usize,
+    partials: usize,
+    failures: usize,
+}
+
+impl Summary {
+    fn new(results: &[Result<Status, Error>]) -> Self {
+        let mut summary = Self::default();
+        for result in results {
+            match result {
+                Ok(Status::Success) => summary.successes += 1,
+                Ok(Status::Partial(_)) => summary.partials += 1,
+                Err(_) => summary.failures += 1,
+            }
+        }
+        summary
+    }
+
+    /// Fails if any of the input files could not be processed. Partial decodes are not
+    /// considered as failures.
+    fn exit_code(&self) -> ExitCode {
+        if self.failures > 0 {
+            ExitCode::FAILURE
+        } else {
+            ExitCode::SUCCESS
+        }
+    }
+
+    /// Prints the outcome of the processing of each input file followed by the number of
+    /// successes, partial decodes and failures.
+    fn print(&self, inputs: &[PathBuf], results: &[Result<Status, Error>]) {
+        eprintln!();
+        for (input, result) in inputs.iter().zip(results) {
+            match result {
+                Ok(Status::Success) => (),
+                Ok(Status::Partial(records)) => eprintln!(
+                    "Partial: {} ({records} record(s) without decode definitions)",
+                    input.display()
+                ),
+                Err(err) => eprintln!("Failed:  {} ({err})", input.display()),
+            }
+        }
+
+        eprintln!(
+            "\n{} file(s) processed: {} succeeded, {} partially decoded, {} failed",
+            inputs.len(),
+            self.successes,
+            self.partials,
+            self.failures
+        );
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::{Options, Status, Summary, is_batch, walk};
+use intel_crashlog::prelude::*;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+const SAMPLES: &str = "../lib/tests/samples";
+
+fn create_dir(name: &str, files: &[&str]) -> PathBuf {
+    let path = std::env::temp_dir().join(name);
+    let _ = std::fs::remove_dir_all(&path);
+    for file in files {
+        let file = path.join(file);
+        std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+        std::fs::write(file, b"").unwrap();
+    }
+    path
+}
+
+#[test]
+fn walk_dir() {
+    let dir = create_dir(
+        "iclg_walk_test",
+        &["b/2.crashlog", "a.crashlog", "b/1.crashlog"],
+    );
+
+    let mut files = Vec::new();
+    walk(&dir, &mut files);
+    assert_eq!(
+        files,
+        [
+            dir.join("a.crashlog"),
+            dir.join("b/1.crashlog"),
+            dir.join("b/2.crashlog")
+        ]
+    );
+
+    let mut files = Vec::new();
+    walk(&dir.join("a.crashlog"), &mut files);
+    assert_eq!(files, [dir.join("a.crashlog")]);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn batch_inputs() {
+    let sample = Path::new(SAMPLES).join("dummy_mca_rev1.crashlog");
+    assert!(!is_batch(std::slice::from_ref(&sample)));
+    assert!(is_batch(&[sample.clone(), sample]));
+    assert!(is_batch(&[PathBuf::from(SAMPLES)]));
+    assert!(is_batch(&[Path::new(SAMPLES).join("*.crashlog")]));
+    assert!(!is_batch(&[PathBuf::from("missing.crashlog")]));
+}
+
+#[test]
+fn summary() {
+    let results = [
+        Ok(Status::Success),
+        Ok(Status::Partial(2)),
+        Ok(Status::Success),
+    ];
+    let summary = Summary::new(&results);
+    assert_eq!(
+        summary,
+        Summary {
+            successes: 2,
+            partials: 1,
+            failures: 0
+        }
+    );
+    assert_eq!(summary.exit_code(), ExitCode::SUCCESS);
+
+    let results = [Ok(Status::Success), Err(Error::InvalidCrashLog)];
+    let summary = Summary::new(&results);
+    assert_eq!(summary.failures, 1);
+    assert_eq!(summary.exit_code(), ExitCode::FAILURE);
+}
+
+#[test]
+fn process_failures() {
+    let options = Options {
+        output_dir: None,
+        output_name: None,
+        jobs: None,
+    };
+    let inputs = [
+        Path::new(SAMPLES).join("dummy_mca_rev1.crashlog"),
+        PathBuf::from("missing.crashlog"),
+    ];
+    let process = |input: &Path, _: &mut dyn std::io::Write| {
+        std::fs::read(input)?;
+        Ok(Status::Success)
+    };
+
+    assert_eq!(
+        options.process(&inputs[..1], false, "", process).unwrap(),
+        ExitCode::SUCCESS
+    );
+    assert_eq!(
+        options.process(&inputs, false, "", process).unwrap(),
+        ExitCode::FAILURE
+    );
+}
@@ -0,0 +1,102 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use crate::lint;
+use clap::Subcommand;
+use intel_crashlog::collateral::InventoryEntry;
+use intel_crashlog::prelude::*;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// List the products and the decode definitions available in the collateral tree
+    List {
+        /// Print the list in JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Check the decode definitions and the target info stored in a collateral tree directory
+    Lint { dir: PathBuf },
+}
+
+impl Command {
+    pub fn run<T: CollateralTree>(&self, cm: &CollateralManager<T>) -> Result<ExitCode, Error> {
+        match self {
+            Command::List { json } => {
+                let inventory = cm.inventory()?;
+                let mut stdout = std::io::stdout().lock();
+                if *json {
+                    serde_json::to_writer_pretty(&mut stdout, &inventory)?;
+                    writeln!(stdout)?;
+                } else {
+                    list(&inventory, stdout)?;
+                }
+                Ok(ExitCode::SUCCESS)
+            }
+            Command::Lint { dir } => Ok(if lint::lint(dir)? {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }),
+        }
+    }
+}
+
+/// Prints the `inventory` as a table with one row per [intel_crashlog::collateral::PVSS].
+fn list(inventory: &[InventoryEntry], mut output: impl Write) -> Result<(), Error> {
+    let header = [
+        "PRODUCT",
+        "VARIANT",
+        "STEPPING",
+        "SECURITY",
+        "PRODUCT ID",
+        "DECODE DEFINITIONS",
+    ]
+    .map(String::from);
+    let rows: Vec<[String; 6]> = inventory
+        .iter()
+        .map(|entry| {
+            let decode_defs: Vec<String> = entry
+                .decode_definitions
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+            [
+                entry.pvss.product.clone(),
+                entry.pvss.variant.clone(),
+                entry.pvss.stepping.clone(),
+                entry.pvss.security.clone(),
+                entry
+                    .product_id
+                    .map_or_else(|| "-".into(), |id| format!("0x{id:03x}")),
+                if decode_defs.is_empty() {
+                    "-".into()
+                } else {
+                    decode_defs.join(", ")
+                },
+            ]
+        })
+        .collect();
+
+    let mut widths = [0; 6];
+    for row in std::iter::once(&header).chain(rows.iter()) {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    for row in std::iter::once(&header).chain(rows.iter()) {
+        let mut line = String::new();
+        for (i, (cell, width)) in row.iter().zip(widths).enumerate() {
+            if i == row.len() - 1 {
+                line.push_str(cell);
+            } else {
+                line.push_str(&format!("{cell:width$}  "));
+            }
+        }
+        writeln!(output, "{line}")?;
+    }
+    Ok(())
+}
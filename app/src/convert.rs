@@ -0,0 +1,74 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use crate::unpack::write_file;
+use clap::ValueEnum;
+use intel_crashlog::prelude::*;
+use std::path::{Path, PathBuf};
+
+/// Container of the converted Crash Log
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    /// ACPI Boot Error Record Table followed by its Boot Error Region
+    Bert,
+    /// Standalone Common Platform Error Record
+    Cper,
+    /// Crash Log records without any container, one file per region
+    Raw,
+}
+
+impl Format {
+    fn extension(self) -> &'static str {
+        match self {
+            Format::Bert => "bert",
+            Format::Cper => "cper",
+            Format::Raw => "crashlog",
+        }
+    }
+}
+
+pub fn convert(input_file: &Path, format: Format, output_path: Option<&Path>) -> Result<(), Error> {
+    let crashlog = CrashLog::from_slice(&std::fs::read(input_file)?)?;
+
+    let path = match output_path {
+        Some(path) => PathBuf::from(path),
+        None => {
+            let mut path = PathBuf::from(input_file);
+            if let Some(filename) = path.file_stem() {
+                path.set_file_name(format!(
+                    "{}_converted.{}",
+                    PathBuf::from(filename).display(),
+                    format.extension()
+                ))
+            }
+            path
+        }
+    };
+
+    match format {
+        Format::Bert => write_file(&path, &crashlog.export(ContainerType::Bert)),
+        Format::Cper => write_file(&path, &crashlog.export(ContainerType::Cper)),
+        Format::Raw => {
+            // The regions are written in separate files, as they cannot be delimited once
+            // concatenated.
+            let regions = crashlog.export_regions();
+            if let [region] = regions.as_slice() {
+                return write_file(&path, region);
+            }
+
+            for (i, region) in regions.iter().enumerate() {
+                let mut path = path.clone();
+                if let Some(filename) = path.file_stem() {
+                    let mut filename = format!("{}_region{i}", PathBuf::from(filename).display());
+                    if let Some(extension) = path.extension() {
+                        filename.push('.');
+                        filename.push_str(&extension.to_string_lossy());
+                    }
+                    path.set_file_name(filename)
+                }
+                write_file(&path, region)?;
+            }
+            Ok(())
+        }
+    }
+}
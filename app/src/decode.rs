@@ -1,24 +1,57 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
+use crate::batch::Status;
 use intel_crashlog::prelude::*;
+use std::io::Write;
 use std::path::Path;
 
-pub fn decode<T: CollateralTree, O: std::io::Write>(
-    cm: &mut CollateralManager<T>,
+pub fn decode<T: CollateralTree, O: Write>(
+    cm: &CollateralManager<T>,
     input: &Path,
     output: O,
-) -> Result<(), Error> {
-    let crashlog = CrashLog::from_slice(&std::fs::read(input)?)?;
-    let nodes = crashlog.decode(cm);
-    Ok(serde_json::to_writer_pretty(output, &nodes)?)
+) -> Result<Status, Error> {
+    let crashlogs = CrashLog::all_from_slice(&std::fs::read(input)?)?;
+    if let [crashlog] = crashlogs.as_slice() {
+        crashlog.decode_to_writer(cm, output)?
+    } else {
+        CrashLog::decode_all_to_writer(&crashlogs, cm, output)?
+    }
+    Ok(Status::of(cm, &crashlogs))
+}
+
+pub fn info<T: CollateralTree, O: Write>(
+    cm: &CollateralManager<T>,
+    input: &Path,
+    mut output: O,
+) -> Result<Status, Error> {
+    let crashlogs = CrashLog::all_from_slice(&std::fs::read(input)?)?;
+    for (i, crashlog) in crashlogs.iter().enumerate() {
+        if crashlogs.len() > 1 {
+            writeln!(output, "{}Crash Log {i}:\n", if i > 0 { "\n" } else { "" })?;
+        }
+        print_crashlog(cm, crashlog, &mut output)?;
+    }
+    Ok(Status::of(cm, &crashlogs))
 }
 
-pub fn info<T: CollateralTree>(cm: &CollateralManager<T>, input: &Path) -> Result<(), Error> {
-    let crashlog = CrashLog::from_slice(&std::fs::read(input)?)?;
+fn print_crashlog<T: CollateralTree, O: Write>(
+    cm: &CollateralManager<T>,
+    crashlog: &CrashLog,
+    output: &mut O,
+) -> Result<(), Error> {
+    if let Some(platform) = crashlog.metadata.platform.as_ref() {
+        writeln!(output, "Platform: {platform}\n")?;
+    }
 
-    println!("  #   Record Type      Rev.  Product  Size   Skt  Checksum  Die      ");
-    println!("----- ---------------- ----- -------- ------ ---- --------- ---------");
+    writeln!(
+        output,
+        "  #   Record Type      Rev.  Product  Size   Skt  Checksum  Die      "
+    )?;
+    writeln!(
+        output,
+        "----- ---------------- ----- -------- ------ ---- --------- ---------"
+    )?;
     for (i, region) in crashlog.regions.iter().enumerate() {
         for (j, record) in region.records.iter().enumerate() {
             let product = if let Ok(product) = record.header.product(cm) {
@@ -48,7 +81,8 @@ pub fn info<T: CollateralTree>(cm: &CollateralManager<T>, input: &Path) -> Resul
                     .unwrap_or_default()
             };
 
-            println!(
+            writeln!(
+                output,
                 "{:>2}-{:<2} {:<16} {:>5} {:<8} {:>6} {:>4} {:<9} {}",
                 i,
                 j,
@@ -59,9 +93,8 @@ pub fn info<T: CollateralTree>(cm: &CollateralManager<T>, input: &Path) -> Resul
                 record.header.socket_id(),
                 checksum,
                 die
-            );
+            )?;
         }
     }
-
     Ok(())
 }
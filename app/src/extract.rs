@@ -6,7 +6,7 @@
 use intel_crashlog::prelude::*;
 use std::path::{Path, PathBuf};
 
-pub fn extract(output_path: Option<&Path>) {
+pub fn extract(output_path: Option<&Path>, with_platform: bool) {
     let mut result: Result<Vec<CrashLog>, Error> = Err(Error::NoCrashLogFound);
 
     #[cfg(target_os = "windows")]
@@ -15,35 +15,44 @@ pub fn extract(output_path: Option<&Path>) {
     }
     #[cfg(target_os = "linux")]
     {
-        result = CrashLog::from_linux_sysfs().map(|crashlog| Vec::from([crashlog]));
+        result = CrashLog::from_linux();
     }
 
     match result {
-        Ok(crashlogs) => {
-            for (i, crashlog) in crashlogs.iter().enumerate() {
-                let mut path = if let Some(output_path) = output_path {
-                    let mut path = output_path.to_path_buf();
-                    if output_path.is_dir() {
-                        path.push(format!("{}.crashlog", crashlog.metadata))
-                    }
-                    path
-                } else {
-                    PathBuf::from(format!("{}.crashlog", crashlog.metadata))
-                };
-
-                if crashlogs.len() > 1
-                    && let Some(filename) = path.file_stem()
-                {
-                    path.set_file_name(format!(
-                        "{}-{i}.crashlog",
-                        PathBuf::from(filename).display()
-                    ))
-                }
-
-                println!("{}", path.display());
-                std::fs::write(path, crashlog.to_bytes()).expect("Failed to write Crash Log file")
+        Ok(crashlogs) => write_crashlogs(&crashlogs, output_path, with_platform),
+        Err(err) => log::error!("Failed to extract Crash Log: {err}"),
+    }
+}
+
+/// Writes the `crashlogs` in separate files. When `with_platform` is set, the platform identity
+/// is embedded in the files, which requires the containers to be rebuilt.
+pub fn write_crashlogs(crashlogs: &[CrashLog], output_path: Option<&Path>, with_platform: bool) {
+    for (i, crashlog) in crashlogs.iter().enumerate() {
+        let mut path = if let Some(output_path) = output_path {
+            let mut path = output_path.to_path_buf();
+            if output_path.is_dir() {
+                path.push(format!("{}.crashlog", crashlog.metadata))
             }
+            path
+        } else {
+            PathBuf::from(format!("{}.crashlog", crashlog.metadata))
+        };
+
+        if crashlogs.len() > 1
+            && let Some(filename) = path.file_stem()
+        {
+            path.set_file_name(format!(
+                "{}-{i}.crashlog",
+                PathBuf::from(filename).display()
+            ))
         }
-        Err(err) => log::error!("Failed to extract Crash Log: {err}"),
+
+        println!("{}", path.display());
+        let bytes = if with_platform && crashlog.metadata.platform.is_some() {
+            crashlog.export_with_platform(ContainerType::Bert)
+        } else {
+            crashlog.to_bytes()
+        };
+        std::fs::write(path, bytes).expect("Failed to write Crash Log file")
     }
 }
@@ -0,0 +1,52 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use crate::extract::write_crashlogs;
+use clap::Args;
+use intel_crashlog::prelude::*;
+use std::path::{Path, PathBuf};
+
+/// Source of the Crash Log records to import
+#[derive(Args)]
+#[group(required = true, multiple = false)]
+pub struct Source {
+    /// ACPI tables dumped by acpidump (text) or acpixtract (binary BERT)
+    #[arg(long, value_name = "file")]
+    acpidump: Option<PathBuf>,
+    /// Linux kernel log (dmesg or journalctl output) reporting BERT/GHES hardware errors
+    #[arg(long, value_name = "file")]
+    kernel_log: Option<PathBuf>,
+    /// Crashdump JSON file collected by the OpenBMC crashdump service
+    #[arg(long, value_name = "file")]
+    openbmc_crashdump: Option<PathBuf>,
+    /// rasdaemon SQLite database (defaults to /var/lib/rasdaemon/ras-mc_event.db)
+    #[arg(long, value_name = "db")]
+    rasdaemon: Option<Option<PathBuf>>,
+}
+
+pub fn import(
+    source: &Source,
+    error_region: Option<&Path>,
+    output_path: Option<&Path>,
+) -> Result<(), Error> {
+    let crashlogs = if let Some(acpidump) = source.acpidump.as_deref() {
+        let region = error_region.map(std::fs::read).transpose()?;
+        Vec::from([CrashLog::from_acpi_dump(
+            &std::fs::read(acpidump)?,
+            region.as_deref(),
+        )?])
+    } else if let Some(kernel_log) = source.kernel_log.as_deref() {
+        CrashLog::from_kernel_log(&std::fs::read_to_string(kernel_log)?)?
+    } else if let Some(crashdump) = source.openbmc_crashdump.as_deref() {
+        Vec::from([CrashLog::from_openbmc_crashdump(&std::fs::read(
+            crashdump,
+        )?)?])
+    } else if let Some(db) = source.rasdaemon.as_ref() {
+        CrashLog::from_rasdaemon(db.as_deref())?
+    } else {
+        return Err(Error::NoCrashLogFound);
+    };
+
+    write_crashlogs(&crashlogs, output_path, false);
+    Ok(())
+}
@@ -0,0 +1,502 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use crate::batch;
+use intel_crashlog::collateral::PVSS;
+use intel_crashlog::prelude::*;
+use intel_crashlog::record::{Layout, LayoutField};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Columns that must be defined in the decode definitions
+const REQUIRED_COLUMNS: [&str; 3] = ["name", "offset", "size"];
+/// Largest product ID that fits in the Crash Log headers
+const MAX_PRODUCT_ID: u32 = 0xFFF;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Severity {
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// Issue found in a file of the collateral tree
+struct Diagnostic {
+    file: PathBuf,
+    line: Option<usize>,
+    severity: Severity,
+    message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.file.display())?;
+        if let Some(line) = self.line {
+            write!(f, ":{line}")?;
+        }
+        write!(f, ": {}: {}", self.severity, self.message)
+    }
+}
+
+/// Returns the name of a field of a decode definition.
+fn name(field: &LayoutField) -> String {
+    field.path.join(".")
+}
+
+/// Returns the offset of the first bit located after a field of a decode definition.
+fn end(field: &LayoutField) -> usize {
+    field.offset + field.size
+}
+
+/// Returns the range of bits covered by a field of a decode definition.
+fn bits(field: &LayoutField) -> String {
+    format!("bits {}..{}", field.offset, end(field))
+}
+
+/// Target info that defines a product ID
+struct Product {
+    file: PathBuf,
+    product: String,
+    variant: String,
+}
+
+#[derive(Default)]
+struct Linter {
+    diagnostics: Vec<Diagnostic>,
+    product_ids: BTreeMap<u32, Product>,
+    files: usize,
+}
+
+impl Linter {
+    fn report(&mut self, severity: Severity, file: &Path, line: Option<usize>, message: String) {
+        self.diagnostics.push(Diagnostic {
+            file: file.to_path_buf(),
+            line,
+            severity,
+            message,
+        });
+    }
+
+    fn error(&mut self, file: &Path, line: Option<usize>, message: String) {
+        self.report(Severity::Error, file, line, message)
+    }
+
+    fn warning(&mut self, file: &Path, line: Option<usize>, message: String) {
+        self.report(Severity::Warning, file, line, message)
+    }
+
+    fn lint_file(&mut self, root: &Path, file: &Path) {
+        let relative = file.strip_prefix(root).unwrap_or(file);
+        let components: Vec<&str> = relative.iter().filter_map(|c| c.to_str()).collect();
+        let is_item = file
+            .extension()
+            .is_some_and(|ext| ext == "csv" || ext == "json");
+
+        match components.as_slice() {
+            [product, variant, stepping, security, "crashlog", item @ ..] if !item.is_empty() => {
+                let pvss = PVSS {
+                    product: product.to_string(),
+                    variant: variant.to_string(),
+                    stepping: stepping.to_string(),
+                    security: security.to_string(),
+                };
+                match item {
+                    ["target_info.json"] => self.lint_target_info(file, &pvss),
+                    [.., name] if name.ends_with(".csv") => self.lint_decode_def(file),
+                    _ => return,
+                }
+                self.files += 1;
+            }
+            _ if is_item => self.warning(
+                file,
+                None,
+                "not located in a PRODUCT/VARIANT/STEPPING/SECURITY/crashlog directory".into(),
+            ),
+            _ => (),
+        }
+    }
+
+    fn read(&mut self, file: &Path) -> Option<String> {
+        match std::fs::read_to_string(file) {
+            Ok(content) => Some(content),
+            Err(err) => {
+                self.error(file, None, format!("cannot read file: {err}"));
+                None
+            }
+        }
+    }
+
+    /// Checks that a decode definition compiles and checks the position of its fields.
+    fn lint_decode_def(&mut self, file: &Path) {
+        let Some(csv) = self.read(file) else {
+            return;
+        };
+        let Some(header) = csv.lines().next() else {
+            self.error(file, None, "empty decode definition".into());
+            return;
+        };
+
+        let columns: Vec<&str> = header.split(';').collect();
+        let missing: Vec<&str> = REQUIRED_COLUMNS
+            .into_iter()
+            .filter(|column| !columns.contains(column))
+            .collect();
+        if !missing.is_empty() {
+            let message = format!("missing required column(s): {}", missing.join(", "));
+            self.error(file, Some(1), message);
+            return;
+        }
+
+        let layout = match Layout::from_csv(csv.as_bytes()) {
+            Ok(layout) => layout,
+            Err(Error::InvalidDecodeDefinition(line, err)) => {
+                let message = format!("Error while parsing integer: {err}");
+                self.error(file, Some(line), message);
+                return;
+            }
+            Err(err) => {
+                self.error(file, None, err.to_string());
+                return;
+            }
+        };
+
+        let mut fields: Vec<&LayoutField> = Vec::new();
+        let mut defined: BTreeMap<&[String], usize> = BTreeMap::new();
+        let mut record: Option<&str> = None;
+
+        for field in layout.fields.iter() {
+            if field.record {
+                record = field.path.first().map(String::as_str);
+            } else {
+                let Some(record) = record else {
+                    let message = format!(
+                        "relative field `{}` without any preceding field",
+                        name(field)
+                    );
+                    self.error(file, Some(field.line), message);
+                    continue;
+                };
+                if field.path.len() < 2 || field.path[0] != record {
+                    let message = format!(
+                        "relative field `{}` is located above the `{record}` record",
+                        name(field)
+                    );
+                    self.error(file, Some(field.line), message);
+                    continue;
+                }
+            }
+
+            if let Some(first) = defined.get(field.path.as_slice()) {
+                let message = format!(
+                    "duplicate field `{}` (first defined at line {})",
+                    name(field),
+                    fields[*first].line
+                );
+                self.error(file, Some(field.line), message);
+                continue;
+            }
+            defined.insert(&field.path, fields.len());
+            fields.push(field);
+        }
+
+        self.check_bounds(file, &fields, &defined);
+        self.check_overlaps(file, &fields);
+    }
+
+    /// Checks that the fields are located within the field that contains them.
+    fn check_bounds(
+        &mut self,
+        file: &Path,
+        fields: &[&LayoutField],
+        defined: &BTreeMap<&[String], usize>,
+    ) {
+        for field in fields {
+            let Some(parent) = (1..field.path.len())
+                .rev()
+                .find_map(|len| defined.get(&field.path[..len]))
+                .map(|i| fields[*i])
+            else {
+                continue;
+            };
+
+            if field.offset < parent.offset || end(field) > end(parent) {
+                let message = if parent.path.len() == 1 {
+                    format!(
+                        "field `{}` ({}) exceeds the size of record `{}` ({} bits)",
+                        name(field),
+                        bits(field),
+                        name(parent),
+                        parent.size
+                    )
+                } else {
+                    format!(
+                        "field `{}` ({}) is located outside of `{}` ({})",
+                        name(field),
+                        bits(field),
+                        name(parent),
+                        bits(parent)
+                    )
+                };
+                self.error(file, Some(field.line), message);
+            }
+        }
+    }
+
+    /// Checks that the fields that are not nested in each other do not overlap. Only the most
+    /// nested of the overlapping fields is reported.
+    fn check_overlaps(&mut self, file: &Path, fields: &[&LayoutField]) {
+        let mut sorted: Vec<&LayoutField> = fields
+            .iter()
+            .copied()
+            .filter(|field| field.size > 0)
+            .collect();
+        sorted.sort_by_key(|field| (field.offset, std::cmp::Reverse(field.size), field.line));
+
+        let mut active: Vec<&LayoutField> = Vec::new();
+        for field in sorted {
+            active.retain(|other| end(other) > field.offset);
+            let overlaps: Vec<&LayoutField> = active
+                .iter()
+                .copied()
+                .filter(|other| {
+                    !field.path.starts_with(&other.path) && !other.path.starts_with(&field.path)
+                })
+                .collect();
+
+            for other in overlaps.iter() {
+                if overlaps.iter().any(|nested| {
+                    nested.path.len() > other.path.len() && nested.path.starts_with(&other.path)
+                }) {
+                    continue;
+                }
+                let (first, second) = if other.line < field.line {
+                    (*other, field)
+                } else {
+                    (field, *other)
+                };
+                let message = format!(
+                    "field `{}` ({}) overlaps `{}` ({}) defined at line {}",
+                    name(second),
+                    bits(second),
+                    name(first),
+                    bits(first),
+                    first.line
+                );
+                self.warning(file, Some(second.line), message);
+            }
+            active.push(field);
+        }
+    }
+
+    /// Checks the product information and the die names defined in a target info file.
+    fn lint_target_info(&mut self, file: &Path, pvss: &PVSS) {
+        let Some(json) = self.read(file) else {
+            return;
+        };
+        let value: Value = match serde_json::from_str(&json) {
+            Ok(value) => value,
+            Err(err) => {
+                let position = format!(" at line {} column {}", err.line(), err.column());
+                let message = err.to_string();
+                let message = message.trim_end_matches(&position);
+                self.error(file, Some(err.line()), format!("invalid JSON: {message}"));
+                return;
+            }
+        };
+        let Value::Object(target_info) = value else {
+            self.error(file, Some(1), "target info must be a JSON object".into());
+            return;
+        };
+        let line = |key: &str| line_of(&json, &format!("\"{key}\""), 1);
+
+        let [product, variant] =
+            [("product", None), ("variant", Some("all"))].map(|(key, default)| {
+                match (target_info.get(key), default) {
+                    (Some(Value::String(value)), _) => Some(value.clone()),
+                    (None, Some(default)) => Some(default.into()),
+                    (None, None) => {
+                        self.error(file, None, format!("missing `{key}`"));
+                        None
+                    }
+                    (Some(_), _) => {
+                        self.error(file, line(key), format!("`{key}` must be a string"));
+                        None
+                    }
+                }
+            });
+        let (Some(product), Some(variant)) = (product, variant) else {
+            return;
+        };
+
+        for (key, value, expected) in [
+            ("product", &product, &pvss.product),
+            ("variant", &variant, &pvss.variant),
+        ] {
+            if value != expected {
+                let message = format!("{key} `{value}` does not match the `{expected}` directory");
+                self.error(file, line(key), message);
+            }
+        }
+
+        match target_info.get("product_id") {
+            Some(Value::String(product_id)) => {
+                let line = line("product_id");
+                match parse_product_id(product_id) {
+                    Some(id) if id > MAX_PRODUCT_ID => {
+                        let message = format!(
+                            "product ID `{product_id}` does not fit in the 12 bits of the Crash Log headers"
+                        );
+                        self.error(file, line, message);
+                    }
+                    Some(id) => self.register_product_id(file, line, id, product, variant),
+                    None => {
+                        let message = format!(
+                            "invalid product ID `{product_id}` (expected a decimal or 0x-prefixed hexadecimal number)"
+                        );
+                        self.error(file, line, message);
+                    }
+                }
+            }
+            Some(_) => {
+                let message = "`product_id` must be a string".into();
+                self.error(file, line("product_id"), message);
+            }
+            None => self.error(file, None, "missing `product_id`".into()),
+        }
+
+        match target_info.get("die_id") {
+            Some(Value::Object(dies)) => {
+                let start = line("die_id").unwrap_or(1);
+                let mut names: BTreeMap<&str, &str> = BTreeMap::new();
+                for (die_id, name) in dies {
+                    let line = line_of(&json, &format!("\"{die_id}\""), start);
+                    if die_id.parse::<u8>().is_err() {
+                        let message = format!("invalid die ID `{die_id}` (expected 0 to 255)");
+                        self.error(file, line, message);
+                    }
+                    let Value::String(name) = name else {
+                        let message = format!("name of die {die_id} must be a string");
+                        self.error(file, line, message);
+                        continue;
+                    };
+                    if name.is_empty() || name.contains('.') {
+                        let message = format!("invalid name `{name}` for die {die_id}");
+                        self.error(file, line, message);
+                    } else if let Some(other) = names.insert(name, die_id) {
+                        let message =
+                            format!("die name `{name}` is used by dies {other} and {die_id}");
+                        self.error(file, line, message);
+                    }
+                }
+            }
+            Some(_) => {
+                let message = "`die_id` must map the die IDs to their names".into();
+                self.error(file, line("die_id"), message);
+            }
+            None => (),
+        }
+    }
+
+    /// Records the product defining the product ID `id`, reporting an error if another product
+    /// already uses it.
+    fn register_product_id(
+        &mut self,
+        file: &Path,
+        line: Option<usize>,
+        id: u32,
+        product: String,
+        variant: String,
+    ) {
+        if let Some(other) = self.product_ids.get(&id) {
+            if other.product != product || other.variant != variant {
+                let message = format!(
+                    "product ID 0x{id:x} is also used by {} ({}) in {}",
+                    other.product,
+                    other.variant,
+                    other.file.display()
+                );
+                self.error(file, line, message);
+            }
+            return;
+        }
+        self.product_ids.insert(
+            id,
+            Product {
+                file: file.to_path_buf(),
+                product,
+                variant,
+            },
+        );
+    }
+}
+
+/// Parses a product ID the same way as the collateral manager.
+fn parse_product_id(product_id: &str) -> Option<u32> {
+    match product_id.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+        None => product_id.parse().ok(),
+    }
+}
+
+/// Returns the number of the first line containing `needle`, starting from line `start`.
+fn line_of(content: &str, needle: &str, start: usize) -> Option<usize> {
+    content
+        .lines()
+        .enumerate()
+        .skip(start - 1)
+        .find(|(_, line)| line.contains(needle))
+        .map(|(i, _)| i + 1)
+}
+
+/// Checks the decode definitions and the target info stored in the collateral tree located in
+/// the `root` directory. The diagnostics are printed on the standard output.
+///
+/// Returns `false` if any error has been found.
+pub fn lint(root: &Path) -> Result<bool, Error> {
+    if !root.is_dir() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("{} is not a directory", root.display()),
+        )
+        .into());
+    }
+
+    let mut files = Vec::new();
+    batch::walk(root, &mut files);
+
+    let mut linter = Linter::default();
+    for file in files.iter() {
+        linter.lint_file(root, file);
+    }
+
+    linter
+        .diagnostics
+        .sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+    for diagnostic in linter.diagnostics.iter() {
+        println!("{diagnostic}");
+    }
+
+    let errors = linter
+        .diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.severity == Severity::Error)
+        .count();
+    let warnings = linter.diagnostics.len() - errors;
+    eprintln!(
+        "{} file(s) checked: {errors} error(s), {warnings} warning(s)",
+        linter.files
+    );
+
+    Ok(errors == 0)
+}
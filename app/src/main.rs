@@ -1,22 +1,45 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
+mod batch;
+mod collateral;
+mod convert;
 mod decode;
 mod extract;
+mod import;
+mod lint;
 mod unpack;
 
 use clap::{Parser, Subcommand};
 use env_logger::Env;
+use intel_crashlog::collateral::{
+    ArchiveTree, EmbeddedTree, FileSystemTree, HttpTree, LayeredTree,
+};
 use intel_crashlog::prelude::*;
 use log::LevelFilter;
 use std::path::PathBuf;
+use std::process::ExitCode;
 
 #[derive(Parser)]
 #[command(version, about = "Extract and decode Intel Crash Log records.")]
 struct Cli {
-    /// Path to the collateral tree. If not specified, the builtin collateral tree will be used.
-    #[arg(short, long, value_name = "dir")]
-    collateral_tree: Option<PathBuf>,
+    /// Path to a collateral tree (directory, .zip, .tar or .tar.gz archive) stacked on top of the
+    /// builtin collateral tree. Can be repeated, in which case the first trees take precedence.
+    #[arg(short, long, value_name = "path")]
+    collateral_tree: Vec<PathBuf>,
+
+    /// URL of a collateral tree published on an HTTP server, used in place of the builtin
+    /// collateral tree for the products it defines
+    #[arg(long, value_name = "url")]
+    collateral_url: Option<String>,
+
+    /// Directory where the collateral items downloaded from the server are cached
+    #[arg(long, value_name = "dir", requires = "collateral_url")]
+    collateral_cache: Option<PathBuf>,
+
+    /// Only uses the collateral items previously downloaded from the server
+    #[arg(long, requires = "collateral_url")]
+    offline: bool,
 
     /// Sets the verbosity of the logging messages
     /// -v: Warning, -vv: Info, -vvv: Debug, -vvvv: Trace
@@ -30,30 +53,85 @@ struct Cli {
 #[derive(Subcommand)]
 enum Command {
     /// Extract the Crash Log records from the platform
-    Extract { output_path: Option<PathBuf> },
+    Extract {
+        /// Embed the platform identity read from the SMBIOS tables in the output files
+        #[arg(long)]
+        with_platform: bool,
+        output_path: Option<PathBuf>,
+    },
     /// Decode Crash Log records into JSON
-    Decode { input_file: PathBuf },
+    Decode {
+        /// Input files, directories, or glob patterns
+        #[arg(required = true)]
+        input_files: Vec<PathBuf>,
+        #[command(flatten)]
+        batch: batch::Options,
+    },
     /// List the Crash Log records stored in the input file
-    Info { input_files: Vec<PathBuf> },
+    Info {
+        /// Input files, directories, or glob patterns
+        input_files: Vec<PathBuf>,
+        #[command(flatten)]
+        batch: batch::Options,
+    },
     /// Unpack the Crash Log records stored in the input file
     Unpack { input_files: Vec<PathBuf> },
+    /// Import the Crash Log records from the output of third-party tools
+    Import {
+        #[command(flatten)]
+        source: import::Source,
+        /// Dump of the Boot Error Region referenced by the BERT
+        #[arg(long, value_name = "file", requires = "acpidump")]
+        error_region: Option<PathBuf>,
+        output_path: Option<PathBuf>,
+    },
+    /// Convert the Crash Log records stored in the input file to another container
+    Convert {
+        /// Container of the output file
+        #[arg(long, value_enum, default_value = "bert")]
+        to: convert::Format,
+        input_file: PathBuf,
+        output_path: Option<PathBuf>,
+    },
+    /// Inspect collateral trees
+    Collateral {
+        #[command(subcommand)]
+        command: collateral::Command,
+    },
 }
 
 impl Command {
-    fn run<T: CollateralTree>(&self, mut cm: CollateralManager<T>) -> Result<(), Error> {
+    fn run<T: CollateralTree + Sync>(&self, cm: CollateralManager<T>) -> Result<ExitCode, Error> {
         match self {
-            Command::Extract { output_path } => extract::extract(output_path.as_deref()),
-            Command::Decode { input_file } => {
-                decode::decode(&mut cm, input_file, std::io::stdout().lock())?
+            Command::Extract {
+                with_platform,
+                output_path,
+            } => extract::extract(output_path.as_deref(), *with_platform),
+            Command::Decode { input_files, batch } => {
+                if let [input_file] = input_files.as_slice()
+                    && !batch::is_batch(input_files)
+                    && !batch.writes_files()
+                {
+                    decode::decode(&cm, input_file, std::io::stdout().lock())?;
+                } else {
+                    return batch.process(input_files, true, "{stem}.json", |input, output| {
+                        decode::decode(&cm, input, output)
+                    });
+                }
             }
-            Command::Info { input_files } => {
-                for input_file in input_files {
-                    if input_files.len() > 1 {
-                        println!("\n{}:\n", input_file.display());
-                    }
-                    if let Err(err) = decode::info(&cm, input_file) {
-                        log::error!("Error: {err}")
-                    }
+            Command::Info { input_files, batch } => {
+                if let [input_file] = input_files.as_slice()
+                    && !batch::is_batch(input_files)
+                    && !batch.writes_files()
+                {
+                    decode::info(&cm, input_file, std::io::stdout().lock())?;
+                } else if !input_files.is_empty() {
+                    return batch.process(
+                        input_files,
+                        batch.writes_files(),
+                        "{stem}.txt",
+                        |input, output| decode::info(&cm, input, output),
+                    );
                 }
             }
             Command::Unpack { input_files } => {
@@ -63,22 +141,55 @@ impl Command {
                     }
                 }
             }
+            Command::Import {
+                source,
+                error_region,
+                output_path,
+            } => import::import(source, error_region.as_deref(), output_path.as_deref())?,
+            Command::Convert {
+                to,
+                input_file,
+                output_path,
+            } => convert::convert(input_file, *to, output_path.as_deref())?,
+            Command::Collateral { command } => return command.run(&cm),
         }
-        Ok(())
+        Ok(ExitCode::SUCCESS)
     }
 }
 
-fn run(cli: Cli) -> Result<(), Error> {
-    if let Some(collateral_tree) = cli.collateral_tree {
-        cli.command
-            .run(CollateralManager::file_system_tree(&collateral_tree)?)?
+/// Returns the default directory where the collateral items downloaded from a server are cached.
+fn default_cache_dir() -> PathBuf {
+    let base = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("LOCALAPPDATA").map(PathBuf::from))
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .unwrap_or_else(std::env::temp_dir);
+    base.join("iclg").join("collateral")
+}
+
+fn run(cli: Cli) -> Result<ExitCode, Error> {
+    if cli.collateral_tree.is_empty() && cli.collateral_url.is_none() {
+        cli.command.run(CollateralManager::embedded_tree()?)
     } else {
-        cli.command.run(CollateralManager::embedded_tree()?)?
+        let mut tree = LayeredTree::default();
+        tree.push(0, EmbeddedTree::new());
+        if let Some(url) = cli.collateral_url.as_deref() {
+            let cache = cli.collateral_cache.unwrap_or_else(default_cache_dir);
+            tree.push(1, HttpTree::new(url, &cache).offline(cli.offline));
+        }
+        for (priority, collateral_tree) in cli.collateral_tree.iter().rev().enumerate() {
+            let priority = priority as i32 + 2;
+            if collateral_tree.is_file() {
+                tree.push(priority, ArchiveTree::from_path(collateral_tree)?);
+            } else {
+                tree.push(priority, FileSystemTree::new(collateral_tree));
+            }
+        }
+        cli.command.run(CollateralManager::layered_tree(tree)?)
     }
-    Ok(())
 }
 
-fn main() {
+fn main() -> ExitCode {
     let cli = Cli::parse();
 
     let log_level = match cli.verbosity {
@@ -91,7 +202,8 @@ fn main() {
 
     env_logger::Builder::from_env(Env::default().default_filter_or(log_level.to_string())).init();
 
-    if let Err(err) = run(cli) {
+    run(cli).unwrap_or_else(|err| {
         log::error!("Fatal Error: {err}");
-    }
+        ExitCode::FAILURE
+    })
 }
@@ -4,7 +4,8 @@
 use intel_crashlog::prelude::*;
 use std::path::{Path, PathBuf};
 
-fn write_file(path: &Path, slice: &[u8]) -> Result<(), Error> {
+/// Writes `slice` to the file located at `path` and prints its path.
+pub fn write_file(path: &Path, slice: &[u8]) -> Result<(), Error> {
     println!("{}", path.display());
     Ok(
         std::fs::write(path, slice)
@@ -30,18 +31,21 @@ fn unpack_evtx(evtx: &Path) -> Result<(), Error> {
 }
 
 fn unpack_crashlog(input_file: &Path) -> Result<(), Error> {
-    let crashlog = CrashLog::from_slice(&std::fs::read(input_file)?)?;
-    let path_prefix = PathBuf::from(input_file);
+    let crashlogs = CrashLog::all_from_slice(&std::fs::read(input_file)?)?;
 
-    for (i, region) in crashlog.regions.iter().enumerate() {
-        let mut path = path_prefix.clone(); // Clone the original path prefix for each iteration
-        if let Some(filename) = path.file_stem() {
-            path.set_file_name(format!(
-                "{}_region{i}.crashlog",
-                PathBuf::from(filename).display()
-            ))
+    for (k, crashlog) in crashlogs.iter().enumerate() {
+        for (i, region) in crashlog.regions.iter().enumerate() {
+            let mut path = PathBuf::from(input_file);
+            if let Some(filename) = path.file_stem() {
+                let prefix = if crashlogs.len() > 1 {
+                    format!("{}-{k}", PathBuf::from(filename).display())
+                } else {
+                    PathBuf::from(filename).display().to_string()
+                };
+                path.set_file_name(format!("{prefix}_region{i}.crashlog"))
+            }
+            write_file(&path, &region.to_bytes())?;
         }
-        write_file(&path, &region.to_bytes())?;
     }
     Ok(())
 }
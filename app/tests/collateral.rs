@@ -0,0 +1,62 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use serde_json::{Value, json};
+use std::process::Command;
+
+#[test]
+fn list_json() {
+    let output = Command::new(env!("CARGO_BIN_EXE_iclg"))
+        .args([
+            "-c",
+            "../lib/tests/collateral",
+            "collateral",
+            "list",
+            "--json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let inventory: Vec<Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let entry = |pvss: Value| {
+        inventory
+            .iter()
+            .find(|entry| entry["pvss"] == pvss)
+            .unwrap_or_else(|| panic!("missing {pvss}"))
+    };
+
+    assert_eq!(
+        *entry(json!({
+            "product": "XYZ",
+            "variant": "all",
+            "stepping": "all",
+            "security": "green"
+        })),
+        json!({
+            "pvss": {
+                "product": "XYZ",
+                "variant": "all",
+                "stepping": "all",
+                "security": "green"
+            },
+            "product_id": 0x7a,
+            "target_info": true,
+            "decode_definitions": [
+                {"record_type": "CRASHLOG_AGENT", "die": "io", "revision": "1"},
+                {"record_type": "MCA", "die": null, "revision": "1"},
+                {"record_type": "MCA", "die": "io", "revision": "2"},
+                {"record_type": "MCA", "die": null, "revision": "all"}
+            ]
+        })
+    );
+
+    let abc = entry(json!({
+        "product": "ABC",
+        "variant": "all",
+        "stepping": "all",
+        "security": "green"
+    }));
+    assert_eq!(abc["product_id"], Value::Null);
+    assert_eq!(abc["decode_definitions"], json!([]));
+}
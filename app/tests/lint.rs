@@ -0,0 +1,79 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use std::process::Command;
+
+/// Runs `iclg collateral lint` on the collateral tree located in `dir`. Returns whether the command
+/// succeeded, the diagnostics it printed and its error output.
+fn lint(dir: &str) -> (bool, Vec<String>, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_iclg"))
+        .args(["collateral", "lint", dir])
+        .output()
+        .unwrap();
+    let diagnostics = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(String::from)
+        .collect();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    (output.status.success(), diagnostics, stderr)
+}
+
+#[test]
+fn valid_tree() {
+    let (success, diagnostics, _) = lint("../lib/collateral");
+    assert!(success);
+    assert!(
+        diagnostics
+            .iter()
+            .all(|diagnostic| diagnostic.contains(": warning: "))
+    );
+}
+
+#[test]
+fn broken_tree() {
+    let (success, diagnostics, _) = lint("tests/broken_collateral");
+    assert!(!success);
+
+    let path = "tests/broken_collateral/BAD/all/all/green/crashlog";
+    assert_eq!(
+        diagnostics,
+        [
+            format!(
+                "{path}/decode-defs/MCA/1/layout.csv:5: error: duplicate field `mca.hdr` (first defined at line 3)"
+            ),
+            format!(
+                "{path}/decode-defs/MCA/1/layout.csv:6: error: field `mca.data` (bits 56..72) exceeds the size of record `mca` (64 bits)"
+            ),
+            format!(
+                "{path}/decode-defs/MCA/1/layout.csv:7: warning: field `mca.flags` (bits 36..44) overlaps `mca.status` (bits 32..40) defined at line 4"
+            ),
+            format!(
+                "{path}/decode-defs/MCA/1/layout.csv:8: error: relative field `misc` is located above the `mca` record"
+            ),
+            format!(
+                "{path}/decode-defs/MCA/2/layout.csv:3: error: Error while parsing integer: invalid digit found in string"
+            ),
+            format!(
+                "{path}/decode-defs/MCA/3/layout.csv:1: error: missing required column(s): size"
+            ),
+            format!(
+                "{path}/target_info.json:3: error: product ID `0x1000` does not fit in the 12 bits of the Crash Log headers"
+            ),
+            format!("{path}/target_info.json:5: error: invalid name `io.0` for die 0"),
+            format!("{path}/target_info.json:6: error: invalid die ID `x` (expected 0 to 255)"),
+            "tests/broken_collateral/stray.csv: warning: not located in a PRODUCT/VARIANT/STEPPING/SECURITY/crashlog directory".into(),
+        ]
+    );
+}
+
+#[test]
+fn missing_tree() {
+    let (success, diagnostics, stderr) = lint("tests/missing");
+    assert!(!success);
+    assert!(diagnostics.is_empty());
+    assert!(
+        stderr.contains("tests/missing is not a directory"),
+        "{stderr}"
+    );
+}
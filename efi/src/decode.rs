@@ -32,7 +32,7 @@ pub fn decode(input_path: &Path) -> Result<(), uefi::Error> {
     let crashlog = read_crashlog_from_file(input_path)?;
 
     let nodes = match CollateralManager::embedded_tree() {
-        Ok(mut cm) => crashlog.decode(&mut cm),
+        Ok(cm) => crashlog.decode(&cm),
         Err(_) => crashlog.decode_without_cm(),
     };
 
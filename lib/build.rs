@@ -1,9 +1,15 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
+#[cfg(feature = "embedded_collateral_tree")]
+extern crate alloc;
 #[cfg(feature = "ffi")]
 extern crate cbindgen;
 
+#[cfg(feature = "embedded_collateral_tree")]
+#[path = "src/record/layout/csv.rs"]
+mod layout;
+
 use std::env;
 
 #[cfg(feature = "embedded_collateral_tree")]
@@ -49,20 +55,110 @@ fn generate_headers() {
     }
 }
 
+/// Selects the items of the collateral tree to embed in the library.
+#[cfg(feature = "embedded_collateral_tree")]
+struct Selection {
+    /// `PRODUCT/VARIANT/STEPPING/SECURITY` patterns, split into their components
+    patterns: Vec<Vec<String>>,
+    /// Security levels
+    security: Vec<String>,
+}
+
+#[cfg(feature = "embedded_collateral_tree")]
+impl Selection {
+    /// Reads the selection from the `CRASHLOG_COLLATERAL_PRODUCTS` and
+    /// `CRASHLOG_COLLATERAL_SECURITY` environment variables. Both variables contain
+    /// comma-separated lists. An empty or undefined list selects everything.
+    fn from_env() -> Self {
+        cargo_emit::rerun_if_env_changed!(
+            "CRASHLOG_COLLATERAL_PRODUCTS",
+            "CRASHLOG_COLLATERAL_SECURITY"
+        );
+
+        let list = |var: &str| -> Vec<String> {
+            env::var(var)
+                .unwrap_or_default()
+                .split(',')
+                .map(str::trim)
+                .filter(|entry| !entry.is_empty())
+                .map(String::from)
+                .collect()
+        };
+
+        let patterns = list("CRASHLOG_COLLATERAL_PRODUCTS")
+            .into_iter()
+            .map(|pattern| {
+                let components: Vec<String> = pattern.split('/').map(String::from).collect();
+                if components.len() > 4 || components.iter().any(String::is_empty) {
+                    panic!("Invalid pattern in CRASHLOG_COLLATERAL_PRODUCTS: {pattern}");
+                }
+                components
+            })
+            .collect();
+
+        Self {
+            patterns,
+            security: list("CRASHLOG_COLLATERAL_SECURITY"),
+        }
+    }
+
+    /// Checks whether the items located under the `PRODUCT/VARIANT/STEPPING/SECURITY` directory
+    /// must be embedded.
+    ///
+    /// The `*` pattern component matches any directory, and the components omitted at the end of
+    /// a pattern are treated as `*`. The `all` directories apply to every product, variant,
+    /// stepping or security level, hence they are always matched.
+    fn matches(&self, pvss: [&str; 4]) -> bool {
+        let matches =
+            |pattern: &str, name: &str| pattern == "*" || name == "all" || pattern == name;
+
+        (self.patterns.is_empty()
+            || self.patterns.iter().any(|pattern| {
+                pattern
+                    .iter()
+                    .zip(pvss)
+                    .all(|(pattern, name)| matches(pattern, name))
+            }))
+            && (self.security.is_empty()
+                || self
+                    .security
+                    .iter()
+                    .any(|security| matches(security, pvss[3])))
+    }
+}
+
 #[cfg(feature = "embedded_collateral_tree")]
 fn embed_collateral_tree() {
+    cargo_emit::rerun_if_env_changed!("CRASHLOG_COLLATERAL_TREE");
     let collateral_tree =
         env::var("CRASHLOG_COLLATERAL_TREE").unwrap_or_else(|_| "collateral".to_string());
     cargo_emit::rerun_if_changed!(collateral_tree);
     cargo_emit::warning!("Embedding collateral tree: {}", collateral_tree);
 
+    let selection = Selection::from_env();
+    if !selection.patterns.is_empty() || !selection.security.is_empty() {
+        cargo_emit::warning!(
+            "Embedding products: {} (security levels: {})",
+            list_or_all(selection.patterns.iter().map(|pattern| pattern.join("/"))),
+            list_or_all(selection.security.iter().cloned())
+        );
+    }
+
     let out_dir = env::var_os("OUT_DIR").unwrap();
     let dest_path = Path::new(&out_dir).join("embedded_collateral_tree.rs");
     let mut file = File::create(dest_path).unwrap();
 
+    // The items are compressed individually and concatenated into a single blob, such that each
+    // item can be decompressed on its own.
+    let mut blob = Vec::new();
+
     let tree_path = std::path::absolute(Path::new(&collateral_tree)).unwrap();
     file.write_all("{\n".as_ref()).unwrap();
     for (product, variant, stepping, security, fullpath) in visit_collateral_tree(&tree_path) {
+        if !selection.matches([&product, &variant, &stepping, &security]) {
+            continue;
+        }
+
         let path = fullpath
             .strip_prefix(
                 tree_path
@@ -78,6 +174,8 @@ fn embed_collateral_tree() {
             .collect::<Vec<&str>>()
             .join("/");
 
+        let content = std::fs::read(&fullpath).unwrap();
+        let item = compress_item(&mut blob, &content);
         file.write_all(
             format!(
                 "    tree.insert_item(
@@ -86,14 +184,105 @@ fn embed_collateral_tree() {
         {stepping:?},
         {security:?},
         {path:?},
-        include_bytes!({fullpath:?})
+        {item}
     );\n"
             )
             .as_ref(),
         )
         .unwrap();
+
+        if fullpath.extension().is_some_and(|ext| ext == "csv") {
+            let Some(layout) = compile_layout(&content) else {
+                cargo_emit::warning!("Cannot compile decode definition: {}", fullpath.display());
+                continue;
+            };
+            let layout = compress_item(&mut blob, &layout);
+            file.write_all(
+                format!(
+                    "    tree.insert_layout(
+        {product:?},
+        {variant:?},
+        {stepping:?},
+        {security:?},
+        {path:?},
+        {layout}
+    );\n"
+                )
+                .as_ref(),
+            )
+            .unwrap();
+        }
     }
     file.write_all("}\n".as_ref()).unwrap();
+
+    std::fs::write(
+        Path::new(&out_dir).join("embedded_collateral_tree.bin"),
+        blob,
+    )
+    .unwrap();
+}
+
+/// Appends the compressed `content` to the `blob` and returns the expression that locates it.
+#[cfg(feature = "embedded_collateral_tree")]
+fn compress_item(blob: &mut Vec<u8>, content: &[u8]) -> String {
+    let compressed = miniz_oxide::deflate::compress_to_vec(content, 10);
+    let (offset, size, length) = (blob.len(), compressed.len(), content.len());
+    blob.extend(compressed);
+    format!("StaticItem {{ offset: {offset}, size: {size}, length: {length} }}")
+}
+
+/// Compiles a CSV-encoded decode definition into the binary encoding read by the embedded tree.
+///
+/// The encoding starts with the number of fields. Each field is then encoded as the number of
+/// path segments shared with the previous field, the remaining path segments, the record flag,
+/// the offset, the size, the line and the description. The integers are encoded as LEB128 and the
+/// strings are prefixed by their length.
+#[cfg(feature = "embedded_collateral_tree")]
+fn compile_layout(csv: &[u8]) -> Option<Vec<u8>> {
+    fn integer(output: &mut Vec<u8>, mut value: usize) {
+        while value >= 0x80 {
+            output.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        output.push(value as u8);
+    }
+
+    fn string(output: &mut Vec<u8>, value: &str) {
+        integer(output, value.len());
+        output.extend(value.as_bytes());
+    }
+
+    let fields = layout::compile(std::str::from_utf8(csv).ok()?).ok()?;
+    let mut output = Vec::new();
+    let mut previous: &[String] = &[];
+
+    integer(&mut output, fields.len());
+    for field in fields.iter() {
+        let shared = previous
+            .iter()
+            .zip(field.path.iter())
+            .take_while(|(previous, segment)| previous == segment)
+            .count();
+        integer(&mut output, shared);
+        integer(&mut output, field.path.len() - shared);
+        for segment in &field.path[shared..] {
+            string(&mut output, segment);
+        }
+        output.push(field.record.into());
+        integer(&mut output, field.offset);
+        integer(&mut output, field.size);
+        integer(&mut output, field.line);
+        string(&mut output, &field.description);
+        previous = &field.path;
+    }
+
+    Some(output)
+}
+
+#[cfg(feature = "embedded_collateral_tree")]
+fn list_or_all(entries: impl Iterator<Item = String>) -> String {
+    let list = entries.collect::<Vec<String>>().join(", ");
+    if list.is_empty() { "all".into() } else { list }
 }
 
 #[cfg(feature = "embedded_collateral_tree")]
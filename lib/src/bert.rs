@@ -1,6 +1,8 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
+//! ACPI Boot Error Record Table (ACPI 6.3 - Section 18.3.1)
+
 pub mod berr;
 #[cfg(test)]
 mod tests;
@@ -12,6 +14,10 @@ use acpi::{
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 pub use berr::Berr;
+#[cfg(not(feature = "std"))]
+use core::mem;
+#[cfg(feature = "std")]
+use std::mem;
 
 /// Boot Error Record Table (ACPI 6.3 - Table 18-381)
 #[repr(C, packed)]
@@ -49,6 +55,52 @@ impl Bert {
         }
     }
 
+    /// Builds a [Bert] referencing the Boot Error Region of `region_length` bytes located at
+    /// `region`. The length and the checksum of the table are computed accordingly.
+    pub fn new(region: u64, region_length: u32) -> Bert {
+        let mut bert = Bert {
+            region,
+            region_length,
+            ..Bert::dummy()
+        };
+        bert.header.length = mem::size_of::<Bert>() as u32;
+        bert.header.revision = 1;
+        bert.update_checksum();
+        bert
+    }
+
+    /// Updates the checksum of the table such that the sum of all its bytes is zero.
+    pub fn update_checksum(&mut self) {
+        let sum = self
+            .to_bytes()
+            .iter()
+            .fold(0u8, |acc, byte| acc.wrapping_add(*byte));
+        self.header.checksum = self.header.checksum.wrapping_sub(sum);
+    }
+
+    /// Decodes the [Bert] stored in a byte slice
+    pub fn from_slice(s: &[u8]) -> Option<Bert> {
+        if !s.starts_with(b"BERT") {
+            return None;
+        }
+
+        Some(Bert {
+            header: SdtHeader {
+                signature: Signature::BERT,
+                length: u32::from_le_bytes(s.get(4..8)?.try_into().ok()?),
+                revision: *s.get(8)?,
+                checksum: *s.get(9)?,
+                oem_id: s.get(10..16)?.try_into().ok()?,
+                oem_table_id: s.get(16..24)?.try_into().ok()?,
+                oem_revision: u32::from_le_bytes(s.get(24..28)?.try_into().ok()?),
+                creator_id: u32::from_le_bytes(s.get(28..32)?.try_into().ok()?),
+                creator_revision: u32::from_le_bytes(s.get(32..36)?.try_into().ok()?),
+            },
+            region_length: u32::from_le_bytes(s.get(36..40)?.try_into().ok()?),
+            region: u64::from_le_bytes(s.get(40..48)?.try_into().ok()?),
+        })
+    }
+
     #[cfg(all(target_os = "uefi", feature = "extraction"))]
     unsafe fn raw_berr_from_phys_mem(&self) -> &[u8] {
         unsafe { core::slice::from_raw_parts(self.region as *const _, self.region_length as usize) }
@@ -66,10 +118,12 @@ impl Bert {
     /// If the [Berr] is not valid, None is returned.
     #[cfg(all(target_os = "uefi", feature = "extraction"))]
     pub unsafe fn berr_from_phys_mem(&self) -> Option<Berr> {
-        Berr::from_slice(unsafe { self.raw_berr_from_phys_mem() })
+        let region = unsafe { self.raw_berr_from_phys_mem() };
+        Berr::from_region(region, Some(region.len()))
     }
 
-    pub(super) fn to_bytes(&self) -> Vec<u8> {
+    /// Exports the [Bert] as a sequence of bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
         let mut bytes = Vec::new();
         bytes.extend_from_slice(b"BERT");
         bytes.extend_from_slice(&self.header.length.to_le_bytes());
@@ -3,8 +3,8 @@
 
 use super::Bert;
 use crate::CrashLog;
-use crate::cper::fer::{FirmwareErrorRecord, FirmwareErrorRecordHeader, RECORD_ID_CRASHLOG};
-use crate::cper::{CperSection, FW_ERROR_RECORD_GUID};
+use crate::cper::CperSection;
+use crate::metadata::{RecordSource, SectionSource, Time, parse_fru_text};
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 #[cfg(not(feature = "std"))]
@@ -49,6 +49,16 @@ impl GenericErrorStatusBlock {
     }
 }
 
+impl From<&GenericErrorStatusBlock> for RecordSource {
+    fn from(block: &GenericErrorStatusBlock) -> Self {
+        RecordSource {
+            severity: block.error_severity,
+            block_status: Some(block.block_status),
+            ..RecordSource::default()
+        }
+    }
+}
+
 /// Generic Error Data Entry (ACPI 6.3 - Table 18-392)
 #[repr(C, packed)]
 #[derive(Debug, Clone, Default)]
@@ -104,6 +114,29 @@ impl GenericErrorDataEntryHeader {
         bytes
     }
 
+    /// Restores the fields of the header that were captured in `source`.
+    fn with_source(mut self, source: Option<&SectionSource>) -> Self {
+        let Some(source) = source else {
+            return self;
+        };
+
+        self.error_severity = source.severity;
+        if let Some(fru_id) = source.fru_id {
+            self.validation_bits |= 1;
+            self.fru_id = fru_id.to_bytes();
+        }
+        if let Some(fru_text) = source.fru_text.as_ref() {
+            self.validation_bits |= 2;
+            let len = fru_text.len().min(self.fru_text.len());
+            self.fru_text[..len].copy_from_slice(&fru_text.as_bytes()[..len]);
+        }
+        if let Some(timestamp) = source.timestamp.as_ref() {
+            self.validation_bits |= 4;
+            self.timestamp = timestamp.to_cper_timestamp();
+        }
+        self
+    }
+
     fn size(&self) -> usize {
         if self.revision >= 0x300 {
             mem::size_of::<Self>()
@@ -113,24 +146,29 @@ impl GenericErrorDataEntryHeader {
     }
 }
 
+impl From<&GenericErrorDataEntryHeader> for SectionSource {
+    fn from(header: &GenericErrorDataEntryHeader) -> Self {
+        SectionSource {
+            severity: header.error_severity,
+            fru_id: (header.validation_bits & 1 != 0)
+                .then_some(uguid::Guid::from_bytes(header.fru_id)),
+            fru_text: (header.validation_bits & 2 != 0)
+                .then(|| parse_fru_text(&header.fru_text))
+                .flatten(),
+            timestamp: (header.revision >= 0x300 && header.validation_bits & 4 != 0)
+                .then(|| Time::from_cper_timestamp(header.timestamp))
+                .flatten(),
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct GenericErrorDataEntry {
     pub header: GenericErrorDataEntryHeader,
     pub cper_section: CperSection,
 }
 
 impl GenericErrorDataEntry {
-    fn from_slice(s: &[u8]) -> Option<Self> {
-        let header = GenericErrorDataEntryHeader::from_slice(s)?;
-        let cper_section = CperSection::from_slice(
-            header.section,
-            s.get(header.size()..header.size() + header.error_data_length as usize)?,
-        )?;
-        Some(Self {
-            header,
-            cper_section,
-        })
-    }
-
     fn to_bytes(&self) -> Vec<u8> {
         let mut header = self.header.clone();
         let mut cper_section = self.cper_section.to_bytes();
@@ -140,46 +178,110 @@ impl GenericErrorDataEntry {
         bytes.append(&mut cper_section);
         bytes
     }
+}
 
-    fn size(&self) -> usize {
-        self.header.size() + self.header.error_data_length as usize
+/// Header and content of a Generic Error Data Entry
+pub(crate) type EntryRef<'a> = (GenericErrorDataEntryHeader, &'a [u8]);
+
+/// Locates the Generic Error Data Entries of a Boot Error Region without copying their content.
+///
+/// Returns the Generic Error Status Block followed by the header and the content of each entry.
+pub(crate) fn entries_from_slice(s: &[u8]) -> Option<(GenericErrorStatusBlock, Vec<EntryRef<'_>>)> {
+    let header = GenericErrorStatusBlock::from_slice(s)?;
+
+    let mut ptr = header.size();
+    let end = ptr + header.data_length as usize;
+    let data = s.get(..end)?;
+    let mut entries = Vec::new();
+
+    while let Some(entry_header) = data
+        .get(ptr..)
+        .and_then(GenericErrorDataEntryHeader::from_slice)
+    {
+        let start = ptr + entry_header.size();
+        let Some(section) = data.get(start..start + entry_header.error_data_length as usize) else {
+            break;
+        };
+        ptr = start + section.len();
+        entries.push((entry_header, section));
+        if ptr >= end {
+            break;
+        }
     }
+
+    Some((header, entries))
 }
 
-#[derive(Default)]
+/// Boot Error Region (ACPI 6.3 - Section 18.3.1)
+#[derive(Clone, Default)]
 pub struct Berr {
     pub header: GenericErrorStatusBlock,
     pub entries: Vec<GenericErrorDataEntry>,
+    /// Bytes of the data area that cannot be decoded as Generic Error Data Entries
+    pub unparsed_data: Vec<u8>,
+    /// Bytes located after the data area (raw error data and unused space of the region)
+    pub raw_data: Vec<u8>,
 }
 
 impl Berr {
     pub fn from_crashlog(crashlog: &CrashLog) -> Berr {
-        let header = GenericErrorStatusBlock::default();
+        let header = crashlog
+            .metadata
+            .source
+            .as_ref()
+            .map(|source| GenericErrorStatusBlock {
+                block_status: source.block_status.unwrap_or_default(),
+                error_severity: source.severity,
+                ..GenericErrorStatusBlock::default()
+            })
+            .unwrap_or_default();
         let entries = crashlog
-            .regions
-            .iter()
-            .map(|region| {
-                let header = GenericErrorDataEntryHeader {
-                    section: FW_ERROR_RECORD_GUID,
-                    revision: 0x300,
-                    ..GenericErrorDataEntryHeader::default()
-                };
-                let cper_section = CperSection::FirmwareErrorRecord(FirmwareErrorRecord {
-                    header: FirmwareErrorRecordHeader {
-                        error_type: 2,
-                        revision: 2,
-                        guid: RECORD_ID_CRASHLOG,
-                        ..FirmwareErrorRecordHeader::default()
-                    },
-                    payload: region.to_bytes(),
-                });
-                GenericErrorDataEntry {
-                    header,
+            .export_sections()
+            .into_iter()
+            .map(
+                |(section_type, source, cper_section)| GenericErrorDataEntry {
+                    header: GenericErrorDataEntryHeader {
+                        section: section_type,
+                        revision: 0x300,
+                        ..GenericErrorDataEntryHeader::default()
+                    }
+                    .with_source(source),
                     cper_section,
-                }
-            })
+                },
+            )
             .collect();
-        Berr { header, entries }
+
+        Berr {
+            header,
+            entries,
+            ..Berr::default()
+        }
+    }
+
+    /// Appends an error section to the region.
+    pub(crate) fn push_section(&mut self, section_type: uguid::Guid, cper_section: CperSection) {
+        self.entries.push(GenericErrorDataEntry {
+            header: GenericErrorDataEntryHeader {
+                section: section_type,
+                revision: 0x300,
+                ..GenericErrorDataEntryHeader::default()
+            },
+            cper_section,
+        });
+    }
+
+    /// Returns the size of the region as declared in its Generic Error Status Block.
+    ///
+    /// The size is not bounded by the actual length of the region, which is not known here.
+    pub fn declared_size(&self) -> usize {
+        let data_end = self.header.size() + self.header.data_length as usize;
+        if self.header.raw_data_length == 0 {
+            data_end
+        } else {
+            let raw_data_end = (self.header.raw_data_offset as usize)
+                .saturating_add(self.header.raw_data_length as usize);
+            data_end.max(raw_data_end)
+        }
     }
 
     pub fn from_bert_file(s: &[u8]) -> Option<Berr> {
@@ -192,22 +294,42 @@ impl Berr {
         }
     }
 
+    /// Decodes the Boot Error Region stored at the beginning of the slice. The region ends at the
+    /// size declared in its Generic Error Status Block.
     pub fn from_slice(s: &[u8]) -> Option<Berr> {
-        let header = GenericErrorStatusBlock::from_slice(s)?;
+        Berr::from_region(s, None)
+    }
+
+    /// Decodes a Boot Error Region of the given `length`, as declared in the [Bert]. When the
+    /// length is not known, the region ends at the size declared in its Generic Error Status
+    /// Block.
+    pub(crate) fn from_region(s: &[u8], length: Option<usize>) -> Option<Berr> {
+        let (header, sections) = entries_from_slice(s)?;
 
         let mut ptr = header.size();
         let end = ptr + header.data_length as usize;
         let mut entries = Vec::new();
 
-        while let Some(entry) = GenericErrorDataEntry::from_slice(s.get(ptr..end)?) {
-            ptr += entry.size();
-            entries.push(entry);
-            if ptr >= end {
+        for (entry_header, section) in sections {
+            let Some(cper_section) = CperSection::from_slice(entry_header.section, section) else {
                 break;
-            }
+            };
+            ptr += entry_header.size() + section.len();
+            entries.push(GenericErrorDataEntry {
+                header: entry_header,
+                cper_section,
+            });
         }
 
-        Some(Berr { header, entries })
+        let mut berr = Berr {
+            header,
+            entries,
+            unparsed_data: Vec::from(s.get(ptr..end).unwrap_or_default()),
+            ..Berr::default()
+        };
+        let length = length.unwrap_or_else(|| berr.declared_size()).min(s.len());
+        berr.raw_data = Vec::from(s.get(end..length).unwrap_or_default());
+        Some(berr)
     }
 
     pub fn to_bytes(&self) -> Vec<u8> {
@@ -220,9 +342,12 @@ impl Berr {
             header.data_length += entry.len() as u32;
             payload.append(&mut entry);
         }
+        header.data_length += self.unparsed_data.len() as u32;
+        payload.extend_from_slice(&self.unparsed_data);
 
         let mut bytes = header.to_bytes();
         bytes.append(&mut payload);
+        bytes.extend_from_slice(&self.raw_data);
         bytes
     }
 }
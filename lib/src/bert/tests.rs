@@ -3,6 +3,7 @@
 
 use super::Berr;
 use crate::CrashLog;
+use crate::metadata::Time;
 
 #[test]
 fn from_slice() {
@@ -30,3 +31,83 @@ fn crashlog_bert() {
     let berr = berr.unwrap();
     assert_eq!(berr.entries.len(), 2);
 }
+
+#[test]
+fn source() {
+    let bert = std::fs::read("tests/samples/dummy.bert").unwrap();
+    let mut crashlog = CrashLog::from_slice(&bert).unwrap();
+    assert!(crashlog.metadata.source.is_some());
+    assert!(
+        crashlog
+            .regions
+            .iter()
+            .all(|region| region.source.is_some())
+    );
+
+    let source = crashlog.regions[0].source.as_mut().unwrap();
+    source.severity = 1;
+    source.fru_text = Some("CPU0".into());
+    source.timestamp = Some(Time {
+        year: 2025,
+        month: 12,
+        day: 31,
+        hour: 12,
+        minute: 34,
+    });
+    crashlog.container = None;
+
+    let crashlog = CrashLog::from_slice(&crashlog.to_bytes()).unwrap();
+    let source = crashlog.regions[0].source.as_ref().unwrap();
+    assert_eq!(source.severity, 1);
+    assert_eq!(source.fru_text.as_deref(), Some("CPU0"));
+    assert_eq!(
+        source.timestamp.as_ref().unwrap().to_string(),
+        "2025-12-31-12-34"
+    );
+
+    let root = crashlog.decode_without_cm();
+    assert_eq!(
+        root.get_by_path("source.region0").unwrap().description,
+        "CPU0"
+    );
+    assert_eq!(
+        root.get_by_path("source.region0.timestamp.year")
+            .unwrap()
+            .kind,
+        crate::node::NodeType::Field { value: 2025 }
+    );
+}
+
+#[test]
+fn raw_data_length() {
+    let bert = std::fs::read("tests/samples/dummy.bert").unwrap();
+    let region = &bert[super::mem::size_of::<super::Bert>()..];
+    let berr = Berr::from_slice(region).unwrap();
+    assert_eq!(berr.to_bytes().len(), berr.declared_size());
+
+    // The bytes located after the region are not part of its raw data.
+    let mut data = Vec::from(region);
+    data.extend_from_slice(&[0xAA; 64]);
+    let berr = Berr::from_slice(&data).unwrap();
+    assert!(!berr.raw_data.contains(&0xAA));
+    assert_eq!(berr.to_bytes(), region[..berr.declared_size()]);
+
+    let berr = Berr::from_region(&data, Some(data.len())).unwrap();
+    assert!(berr.raw_data.ends_with(&[0xAA; 64]));
+}
+
+#[test]
+fn raw_data_overflow() {
+    let mut data = Vec::from(*b"BERR");
+    data.extend_from_slice(&0u32.to_le_bytes());
+    data.extend_from_slice(&0xFFFF_FFF0u32.to_le_bytes());
+    data.extend_from_slice(&0x100u32.to_le_bytes());
+    data.extend_from_slice(&0u32.to_le_bytes());
+    data.extend_from_slice(&0u32.to_le_bytes());
+    assert_eq!(data.len(), 24);
+
+    let berr = Berr::from_slice(&data[4..]).unwrap();
+    assert!(berr.raw_data.is_empty());
+    assert!(CrashLog::from_slice(&data).is_err());
+    assert!(CrashLog::all_from_slice(&data).is_err());
+}
@@ -9,24 +9,42 @@
 //! - [`PVSS`]: uniquely identifies a product.
 //! - [`ItemPath`]: defines the location of the item within a given [`PVSS`]
 
+#[cfg(feature = "archive_collateral_tree")]
+mod archive;
 #[cfg(feature = "embedded_collateral_tree")]
 mod embedded;
 #[cfg(feature = "fs_collateral_tree")]
 mod fs;
+/// cbindgen:ignore
+#[cfg(feature = "http_collateral_tree")]
+mod http;
+mod inventory;
+mod layered;
+mod memory;
 mod path;
 mod pvss;
 mod target_info;
 
 use crate::Error;
 use crate::header::Header;
-use crate::utils::Map;
+use crate::record::Layout;
+use crate::utils::{Lock, Map};
 #[cfg(not(feature = "std"))]
-use alloc::{string::ToString, vec::Vec};
+use alloc::{string::ToString, sync::Arc, vec::Vec};
+#[cfg(feature = "std")]
+use std::sync::Arc;
 
+#[cfg(feature = "archive_collateral_tree")]
+pub use archive::ArchiveTree;
 #[cfg(feature = "embedded_collateral_tree")]
 pub use embedded::EmbeddedTree;
 #[cfg(feature = "fs_collateral_tree")]
 pub use fs::FileSystemTree;
+#[cfg(feature = "http_collateral_tree")]
+pub use http::HttpTree;
+pub use inventory::{DecodeDefinition, InventoryEntry};
+pub use layered::LayeredTree;
+pub use memory::MemoryTree;
 pub use path::ItemPath;
 pub use pvss::PVSS;
 pub use target_info::TargetInfo;
@@ -44,13 +62,41 @@ pub trait CollateralTree {
     fn get(&self, pvss: &PVSS, path: &ItemPath) -> Result<Vec<u8>, Error>;
     /// Returns a list of all the `PVSS` that have an item defined at the given `path`.
     fn search(&self, path: &ItemPath) -> Result<Vec<PVSS>, Error>;
+    /// Returns all the items located under the given `path`, alongside the `PVSS` that defines
+    /// them.
+    fn list(&self, path: &ItemPath) -> Result<Vec<(PVSS, ItemPath)>, Error>;
+    /// Returns the compiled decode definition stored at the given `path`.
+    ///
+    /// By default, the decode definition is compiled from the content of the item.
+    fn get_layout(&self, pvss: &PVSS, path: &ItemPath) -> Result<Layout, Error> {
+        Layout::from_csv(&self.get(pvss, path)?)
+    }
 }
 
 /// Manages the product-specific collateral files required to decode the Crash Log records.
+///
+/// The items fetched from the collateral tree are cached behind a lock, such that a single
+/// collateral manager can be shared by several threads decoding Crash Log records concurrently.
+/// The collateral manager is [Send] and [Sync] as long as its collateral tree is.
+///
+/// ```
+/// use intel_crashlog::prelude::*;
+///
+/// let data = std::fs::read("tests/samples/three_strike_timeout.crashlog").unwrap();
+/// let crashlog = CrashLog::from_slice(&data).unwrap();
+/// let cm = CollateralManager::embedded_tree().unwrap();
+///
+/// std::thread::scope(|s| {
+///     let first = s.spawn(|| crashlog.decode(&cm));
+///     let second = s.spawn(|| crashlog.decode(&cm));
+///     assert_eq!(first.join().unwrap(), second.join().unwrap());
+/// });
+/// ```
 #[derive(Default)]
 pub struct CollateralManager<T: CollateralTree> {
     tree: T,
-    items: Map<ItemIndex, Vec<u8>>,
+    items: Lock<Map<ItemIndex, Arc<[u8]>>>,
+    layouts: Lock<Map<ItemIndex, Arc<Layout>>>,
     /// Maps the Crash Log product IDs into a data structure that stores various information
     /// about the associated product.
     pub target_info: Map<u32, TargetInfo>,
@@ -61,20 +107,37 @@ impl<T: CollateralTree> CollateralManager<T> {
     pub fn new(tree: T) -> Result<Self, Error> {
         let mut cm = Self {
             tree,
-            items: Map::default(),
+            items: Lock::default(),
+            layouts: Lock::default(),
             target_info: Map::default(),
         };
         cm.update_target_info()?;
         Ok(cm)
     }
 
+    /// Returns the collateral tree used by the collateral manager.
+    pub fn tree(&self) -> &T {
+        &self.tree
+    }
+
+    /// Modifies the collateral tree used by the collateral manager.
+    ///
+    /// The items cached by the collateral manager are discarded and the target info are reloaded
+    /// from the updated tree.
+    pub fn update_tree(&mut self, update: impl FnOnce(&mut T)) -> Result<(), Error> {
+        update(&mut self.tree);
+        self.items = Lock::default();
+        self.layouts = Lock::default();
+        self.update_target_info()
+    }
+
     /// Returns the content of an item from the collateral tree using the [`PVSS`] of the target.
     ///
     /// ```
     /// use intel_crashlog::prelude::*;
     /// use intel_crashlog::collateral::PVSS;
     ///
-    /// let mut cm = CollateralManager::embedded_tree().unwrap();
+    /// let cm = CollateralManager::embedded_tree().unwrap();
     /// let pvss = PVSS {
     ///     product: "XYZ".into(),
     ///     ..PVSS::default()
@@ -82,26 +145,31 @@ impl<T: CollateralTree> CollateralManager<T> {
     /// assert!(cm.get_item_with_pvss(pvss, "target_info.json").is_ok());
     /// ```
     pub fn get_item_with_pvss(
-        &mut self,
+        &self,
         pvss: PVSS,
         path: impl Into<ItemPath>,
-    ) -> Result<&[u8], Error> {
+    ) -> Result<Arc<[u8]>, Error> {
         let index = ItemIndex {
             pvss,
             path: path.into(),
         };
 
-        if !self.items.contains_key(&index) {
-            self.fetch_item(&index)?;
+        if let Some(item) = self.items.read().get(&index) {
+            return Ok(item.clone());
         }
 
-        self.items
-            .get(&index)
-            .map(|i| i.as_ref())
-            .ok_or(Error::InternalError)
+        let item: Arc<[u8]> = self.fetch(&index, T::get)?.into();
+        self.items.write().insert(index, item.clone());
+        Ok(item)
     }
 
-    fn fetch_item(&mut self, index: &ItemIndex) -> Result<(), Error> {
+    /// Fetches an item from the collateral tree. The items defined with a less restrictive
+    /// security level are used as fallbacks.
+    fn fetch<I>(
+        &self,
+        index: &ItemIndex,
+        get: impl Fn(&T, &PVSS, &ItemPath) -> Result<I, Error>,
+    ) -> Result<I, Error> {
         let security_levels = ["red", "white", "green", "all"];
 
         if let Some(i) = security_levels
@@ -113,11 +181,8 @@ impl<T: CollateralTree> CollateralManager<T> {
                     security: security.to_string(),
                     ..index.pvss.clone()
                 };
-                match self.tree.get(&pvss, &index.path) {
-                    Ok(item) => {
-                        self.items.insert(index.clone(), item);
-                        return Ok(());
-                    }
+                match get(&self.tree, &pvss, &index.path) {
+                    Ok(item) => return Ok(item),
                     Err(Error::MissingCollateral(_, item)) => {
                         log::debug!("No {security} {item} defined")
                     }
@@ -138,7 +203,7 @@ impl<T: CollateralTree> CollateralManager<T> {
     /// use intel_crashlog::prelude::*;
     /// use intel_crashlog::collateral::PVSS;
     ///
-    /// let mut cm = CollateralManager::embedded_tree().unwrap();
+    /// let cm = CollateralManager::embedded_tree().unwrap();
     /// let pvss = PVSS {
     ///     product: "XYZ".into(),
     ///     ..PVSS::default()
@@ -146,24 +211,22 @@ impl<T: CollateralTree> CollateralManager<T> {
     /// assert!(cm.get_item_with_pvss(pvss, "target_info.json").is_ok());
     /// ```
     pub fn get_item_with_pvs(
-        &mut self,
+        &self,
         pvss: PVSS,
         path: impl Into<ItemPath>,
-    ) -> Result<&[u8], Error> {
+    ) -> Result<Arc<[u8]>, Error> {
         let index = ItemIndex {
             pvss,
             path: path.into(),
         };
 
-        if !self.items.contains_key(&index) {
-            let item = self.tree.get(&index.pvss, &index.path)?;
-            self.items.insert(index.clone(), item);
+        if let Some(item) = self.items.read().get(&index) {
+            return Ok(item.clone());
         }
 
-        self.items
-            .get(&index)
-            .map(|i| i.as_ref())
-            .ok_or(Error::InternalError)
+        let item: Arc<[u8]> = self.tree.get(&index.pvss, &index.path)?.into();
+        self.items.write().insert(index, item.clone());
+        Ok(item)
     }
 
     /// Returns the content of an item from the collateral tree using the Crash Log header.
@@ -171,16 +234,60 @@ impl<T: CollateralTree> CollateralManager<T> {
     /// ```
     /// use intel_crashlog::prelude::*;
     ///
-    /// let mut cm = CollateralManager::embedded_tree().unwrap();
+    /// let cm = CollateralManager::embedded_tree().unwrap();
     /// let data = vec![0x08, 0xa1, 0x07, 0x3e, 0x2, 0x0, 0x0, 0x0];
     /// let header = Header::from_slice(&data).unwrap().unwrap();
     /// assert!(cm.get_item_with_header(&header, "target_info.json").is_ok());
     /// ```
     pub fn get_item_with_header(
-        &mut self,
+        &self,
         header: &Header,
         path: impl Into<ItemPath>,
-    ) -> Result<&[u8], Error> {
+    ) -> Result<Arc<[u8]>, Error> {
         self.get_item_with_pvss(header.pvss(self)?, path)
     }
+
+    /// Returns the compiled decode definition stored at the given `path` using the [`PVSS`] of
+    /// the target.
+    ///
+    /// The decode definitions are compiled once and cached by the collateral manager.
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    /// use intel_crashlog::collateral::PVSS;
+    ///
+    /// let cm = CollateralManager::embedded_tree().unwrap();
+    /// let layout = cm
+    ///     .get_layout_with_pvss(PVSS::default(), "decode-defs/CRASHLOG_AGENT/1/layout.csv")
+    ///     .unwrap();
+    /// assert!(!layout.fields.is_empty());
+    /// ```
+    pub fn get_layout_with_pvss(
+        &self,
+        pvss: PVSS,
+        path: impl Into<ItemPath>,
+    ) -> Result<Arc<Layout>, Error> {
+        let index = ItemIndex {
+            pvss,
+            path: path.into(),
+        };
+
+        if let Some(layout) = self.layouts.read().get(&index) {
+            return Ok(layout.clone());
+        }
+
+        let layout = Arc::new(self.fetch(&index, T::get_layout)?);
+        self.layouts.write().insert(index, layout.clone());
+        Ok(layout)
+    }
+
+    /// Returns the compiled decode definition stored at the given `path` using the Crash Log
+    /// header.
+    pub fn get_layout_with_header(
+        &self,
+        header: &Header,
+        path: impl Into<ItemPath>,
+    ) -> Result<Arc<Layout>, Error> {
+        self.get_layout_with_pvss(header.pvss(self)?, path)
+    }
 }
@@ -0,0 +1,157 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::{CollateralManager, CollateralTree, ItemPath, MemoryTree, PVSS};
+use crate::Error;
+use std::io::{Cursor, Read, Seek};
+use std::path::{Component, Path};
+
+/// Magic number found at the beginning of the zip archives
+const ZIP_MAGIC: &[u8] = b"PK\x03\x04";
+/// Magic number found at the beginning of the gzip streams
+const GZIP_MAGIC: &[u8] = b"\x1f\x8b";
+
+/// Provides access to a collateral tree stored in a `.zip`, `.tar` or `.tar.gz` archive.
+///
+/// The files in the archive must follow the same structure as the [`super::FileSystemTree`]:
+/// `PRODUCT/VARIANT/STEPPING/SECURITY/crashlog/ITEM_PATH`. This structure can be nested in any
+/// number of parent directories.
+///
+/// The content of the archive is loaded in memory when the tree is created.
+///
+/// # Examples
+///
+/// ```
+/// use intel_crashlog::prelude::*;
+/// use std::path::Path;
+///
+/// let cm = CollateralManager::archive_tree(Path::new("tests/samples/collateral.zip")).unwrap();
+/// assert_eq!(cm.target_info.get(&0x07A).unwrap().product, "XYZ");
+/// ```
+#[derive(Default)]
+pub struct ArchiveTree {
+    items: MemoryTree,
+}
+
+impl ArchiveTree {
+    /// Reads the collateral tree stored in the archive located at `path`.
+    ///
+    /// The format of the archive is detected from its content.
+    pub fn from_path(path: &Path) -> Result<Self, Error> {
+        Self::from_slice(&std::fs::read(path)?)
+    }
+
+    /// Reads the collateral tree stored in an archive loaded in memory.
+    ///
+    /// The format of the archive is detected from its content.
+    pub fn from_slice(archive: &[u8]) -> Result<Self, Error> {
+        if archive.starts_with(ZIP_MAGIC) {
+            Self::from_zip(Cursor::new(archive))
+        } else if archive.starts_with(GZIP_MAGIC) {
+            Self::from_tar(flate2::read::GzDecoder::new(archive))
+        } else {
+            Self::from_tar(archive)
+        }
+    }
+
+    /// Reads the collateral tree stored in a zip archive.
+    pub fn from_zip(reader: impl Read + Seek) -> Result<Self, Error> {
+        let mut tree = Self::default();
+        let mut archive = zip::ZipArchive::new(reader)?;
+        for i in 0..archive.len() {
+            let mut file = archive.by_index(i)?;
+            if !file.is_file() {
+                continue;
+            }
+            let Some(path) = file.enclosed_name() else {
+                log::warn!("Ignoring unsafe path in archive: {}", file.name());
+                continue;
+            };
+            let mut content = Vec::new();
+            file.read_to_end(&mut content)?;
+            tree.insert(&path, content);
+        }
+        Ok(tree)
+    }
+
+    /// Reads the collateral tree stored in an uncompressed tar archive. The archive can be
+    /// decompressed on the fly by passing a decoder as `reader`.
+    pub fn from_tar(reader: impl Read) -> Result<Self, Error> {
+        let mut tree = Self::default();
+        let mut archive = tar::Archive::new(reader);
+        for entry in archive.entries()? {
+            let mut entry = entry?;
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+            let path = entry.path()?.into_owned();
+            let mut content = Vec::new();
+            entry.read_to_end(&mut content)?;
+            tree.insert(&path, content);
+        }
+        Ok(tree)
+    }
+
+    /// Registers a file of the archive located at `path` in the collateral tree. Files located
+    /// outside of a `PRODUCT/VARIANT/STEPPING/SECURITY/crashlog` directory are ignored.
+    fn insert(&mut self, path: &Path, content: Vec<u8>) {
+        let mut components = Vec::new();
+        for component in path.components() {
+            match component {
+                Component::Normal(name) => match name.to_str() {
+                    Some(name) => components.push(name),
+                    None => return,
+                },
+                Component::CurDir => (),
+                _ => return,
+            }
+        }
+
+        let Some(base) = components
+            .iter()
+            .enumerate()
+            .skip(4)
+            .find_map(|(i, name)| (*name == "crashlog").then_some(i))
+        else {
+            log::debug!("Ignoring file in archive: {}", path.display());
+            return;
+        };
+
+        let (pvss, item) = (&components[base - 4..base], &components[base + 1..]);
+        if item.is_empty() {
+            return;
+        }
+
+        let pvss = PVSS {
+            product: pvss[0].into(),
+            variant: pvss[1].into(),
+            stepping: pvss[2].into(),
+            security: pvss[3].into(),
+        };
+        let item: ItemPath = item.join("/").as_str().into();
+        log::trace!("Loading {item} ({pvss}) from archive");
+        self.items.insert(pvss, item, content);
+    }
+}
+
+impl CollateralTree for ArchiveTree {
+    fn get(&self, pvss: &PVSS, item: &ItemPath) -> Result<Vec<u8>, Error> {
+        self.items.get(pvss, item)
+    }
+
+    fn search(&self, item: &ItemPath) -> Result<Vec<PVSS>, Error> {
+        self.items.search(item)
+    }
+
+    fn list(&self, path: &ItemPath) -> Result<Vec<(PVSS, ItemPath)>, Error> {
+        self.items.list(path)
+    }
+}
+
+impl CollateralManager<ArchiveTree> {
+    /// Creates a [`CollateralManager`] that uses a collateral tree stored in an archive. The
+    /// content of the archive located at `path` is loaded in memory.
+    pub fn archive_tree(path: &Path) -> Result<Self, Error> {
+        Self::new(ArchiveTree::from_path(path)?)
+    }
+}
@@ -3,18 +3,119 @@
 
 use super::{CollateralManager, CollateralTree, ItemPath, PVSS};
 use crate::Error;
+use crate::record::{Layout, LayoutField};
 use crate::utils::Map;
 #[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
+
+/// Items of the collateral tree compressed by the build script
+static ITEMS: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/embedded_collateral_tree.bin"));
+
+/// Location of a compressed item in [ITEMS]
+#[derive(Clone, Copy)]
+struct StaticItem {
+    /// Offset of the compressed item in bytes
+    offset: usize,
+    /// Size of the compressed item in bytes
+    size: usize,
+    /// Size of the decompressed item in bytes
+    length: usize,
+}
+
+impl StaticItem {
+    fn decompress(&self) -> Result<Vec<u8>, Error> {
+        let data = ITEMS
+            .get(self.offset..self.offset + self.size)
+            .ok_or(Error::InternalError)?;
+        miniz_oxide::inflate::decompress_to_vec_with_limit(data, self.length)
+            .map_err(|_| Error::InternalError)
+    }
+}
+
+/// Reads the decode definitions compiled by the build script.
+///
+/// See `compile_layout` in `build.rs` for the description of the encoding.
+struct LayoutReader<'a> {
+    data: &'a [u8],
+}
+
+impl LayoutReader<'_> {
+    fn byte(&mut self) -> Result<u8, Error> {
+        let (byte, data) = self.data.split_first().ok_or(Error::InternalError)?;
+        self.data = data;
+        Ok(*byte)
+    }
+
+    fn integer(&mut self) -> Result<usize, Error> {
+        let mut value = 0;
+        for shift in (0..usize::BITS).step_by(7) {
+            let byte = self.byte()?;
+            value |= ((byte & 0x7f) as usize) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(Error::InternalError)
+    }
+
+    fn string(&mut self) -> Result<String, Error> {
+        let length = self.integer()?;
+        let (string, data) = self
+            .data
+            .split_at_checked(length)
+            .ok_or(Error::InternalError)?;
+        self.data = data;
+        Ok(core::str::from_utf8(string)?.into())
+    }
+
+    fn layout(&mut self) -> Result<Layout, Error> {
+        let count = self.integer()?;
+        let mut fields: Vec<LayoutField> = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            let mut path = match (fields.last(), self.integer()?) {
+                (_, 0) => Vec::new(),
+                (Some(previous), shared) => previous
+                    .path
+                    .get(..shared)
+                    .ok_or(Error::InternalError)?
+                    .to_vec(),
+                (None, _) => return Err(Error::InternalError),
+            };
+            for _ in 0..self.integer()? {
+                path.push(self.string()?);
+            }
+
+            fields.push(LayoutField {
+                path,
+                record: self.byte()? != 0,
+                offset: self.integer()?,
+                size: self.integer()?,
+                line: self.integer()?,
+                description: self.string()?,
+            });
+        }
+
+        Ok(Layout { fields })
+    }
+}
 
 /// Provides access to a collateral tree embedded in the library.
+///
+/// The items are stored compressed and are decompressed when fetched. The decode definitions are
+/// also compiled when the library is built, such that they do not need to be parsed again at
+/// runtime.
 #[derive(Default)]
 pub struct EmbeddedTree {
-    registry: Map<PVSS, Map<ItemPath, &'static [u8]>>,
+    registry: Map<PVSS, Map<ItemPath, StaticItem>>,
+    layouts: Map<PVSS, Map<ItemPath, StaticItem>>,
 }
 
 impl EmbeddedTree {
-    fn new() -> Self {
+    /// Creates a collateral tree that contains the items embedded in the library.
+    ///
+    /// Note that the tree returned by [EmbeddedTree::default] is empty.
+    pub fn new() -> Self {
         let mut tree = Self::default();
         include!(concat!(env!("OUT_DIR"), "/embedded_collateral_tree.rs"));
         tree
@@ -27,7 +128,38 @@ impl EmbeddedTree {
         stepping: &str,
         security: &str,
         path: &str,
-        content: &'static [u8],
+        item: StaticItem,
+    ) {
+        Self::insert(
+            &mut self.registry,
+            [product, variant, stepping, security],
+            path,
+            item,
+        );
+    }
+
+    fn insert_layout(
+        &mut self,
+        product: &str,
+        variant: &str,
+        stepping: &str,
+        security: &str,
+        path: &str,
+        layout: StaticItem,
+    ) {
+        Self::insert(
+            &mut self.layouts,
+            [product, variant, stepping, security],
+            path,
+            layout,
+        );
+    }
+
+    fn insert(
+        registry: &mut Map<PVSS, Map<ItemPath, StaticItem>>,
+        [product, variant, stepping, security]: [&str; 4],
+        path: &str,
+        item: StaticItem,
     ) {
         let pvss = PVSS {
             product: product.into(),
@@ -35,12 +167,12 @@ impl EmbeddedTree {
             stepping: stepping.into(),
             security: security.into(),
         };
-        if !self.registry.contains_key(&pvss) {
-            self.registry.insert(pvss.clone(), Map::default());
+        if !registry.contains_key(&pvss) {
+            registry.insert(pvss.clone(), Map::default());
         }
 
-        if let Some(items) = self.registry.get_mut(&pvss) {
-            items.insert(path.parse().unwrap(), content);
+        if let Some(items) = registry.get_mut(&pvss) {
+            items.insert(path.parse().unwrap(), item);
         }
     }
 }
@@ -55,7 +187,7 @@ impl CollateralTree for EmbeddedTree {
                     .get(item)
                     .ok_or_else(|| Error::MissingCollateral(pvss.clone(), item.clone()))
             })
-            .map(|data| Vec::from(*data))
+            .and_then(StaticItem::decompress)
     }
 
     fn search(&self, item: &ItemPath) -> Result<Vec<PVSS>, Error> {
@@ -69,6 +201,26 @@ impl CollateralTree for EmbeddedTree {
 
         Ok(hits)
     }
+
+    fn list(&self, path: &ItemPath) -> Result<Vec<(PVSS, ItemPath)>, Error> {
+        Ok(self
+            .registry
+            .iter()
+            .flat_map(|(pvss, items)| items.keys().map(move |item| (pvss, item)))
+            .filter(|(_, item)| item.starts_with(path))
+            .map(|(pvss, item)| (pvss.clone(), item.clone()))
+            .collect())
+    }
+
+    fn get_layout(&self, pvss: &PVSS, item: &ItemPath) -> Result<Layout, Error> {
+        match self.layouts.get(pvss).and_then(|layouts| layouts.get(item)) {
+            Some(layout) => LayoutReader {
+                data: &layout.decompress()?,
+            }
+            .layout(),
+            None => Layout::from_csv(&self.get(pvss, item)?),
+        }
+    }
 }
 
 impl CollateralManager<EmbeddedTree> {
@@ -18,7 +18,8 @@ pub struct FileSystemTree {
 }
 
 impl FileSystemTree {
-    fn new(root: &Path) -> Self {
+    /// Creates a collateral tree that reads the items stored under the `root` directory.
+    pub fn new(root: &Path) -> Self {
         Self {
             root: root.to_path_buf(),
         }
@@ -40,6 +41,33 @@ impl FileSystemTree {
             .ok()
             .filter(|path| path.starts_with(&base))
     }
+
+    /// Returns the `crashlog` directory of each [`PVSS`] defined in the collateral tree.
+    fn directories(&self) -> Result<Vec<(PVSS, PathBuf)>, Error> {
+        let mut directories = Vec::new();
+
+        for product in std::fs::read_dir(&self.root)? {
+            let product = product?;
+            for variant in std::fs::read_dir(product.path())? {
+                let variant = variant?;
+                for stepping in std::fs::read_dir(variant.path())? {
+                    let stepping = stepping?;
+                    for security in std::fs::read_dir(stepping.path())? {
+                        let security = security?;
+                        let pvss = PVSS {
+                            product: product.file_name().into_string()?,
+                            variant: variant.file_name().into_string()?,
+                            stepping: stepping.file_name().into_string()?,
+                            security: security.file_name().into_string()?,
+                        };
+                        directories.push((pvss, security.path().join("crashlog")));
+                    }
+                }
+            }
+        }
+
+        Ok(directories)
+    }
 }
 
 impl CollateralTree for FileSystemTree {
@@ -55,34 +83,40 @@ impl CollateralTree for FileSystemTree {
     }
 
     fn search(&self, item: &ItemPath) -> Result<Vec<PVSS>, Error> {
-        let mut hits = Vec::new();
+        let item: PathBuf = item.into();
+        Ok(self
+            .directories()?
+            .into_iter()
+            .filter(|(_, base)| base.join(&item).exists())
+            .map(|(pvss, _)| pvss)
+            .collect())
+    }
 
-        for product in std::fs::read_dir(&self.root)? {
-            let product = product?;
-            for variant in std::fs::read_dir(product.path())? {
-                let variant = variant?;
-                for stepping in std::fs::read_dir(variant.path())? {
-                    let stepping = stepping?;
-                    for security in std::fs::read_dir(stepping.path())? {
-                        let security = security?;
-                        let mut path = security.path();
-                        path.push("crashlog");
-                        path.push::<PathBuf>(item.into());
-                        if path.exists() {
-                            hits.push(PVSS {
-                                product: product.file_name().into_string()?,
-                                variant: variant.file_name().into_string()?,
-                                stepping: stepping.file_name().into_string()?,
-                                security: security.file_name().into_string()?,
-                            });
-                        }
-                    }
-                }
-            }
+    fn list(&self, path: &ItemPath) -> Result<Vec<(PVSS, ItemPath)>, Error> {
+        let mut items = Vec::new();
+        for (pvss, base) in self.directories()? {
+            let mut found = Vec::new();
+            visit(&base.join(PathBuf::from(path)), path.clone(), &mut found)?;
+            items.extend(found.into_iter().map(|item| (pvss.clone(), item)));
         }
+        Ok(items)
+    }
+}
 
-        Ok(hits)
+/// Appends to `items` the files located under `path`, whose location in the collateral tree is
+/// `item`.
+fn visit(path: &Path, item: ItemPath, items: &mut Vec<ItemPath>) -> Result<(), Error> {
+    if path.is_file() {
+        items.push(item);
+    } else if path.is_dir() {
+        for entry in std::fs::read_dir(path)? {
+            let entry = entry?;
+            let mut item = item.clone();
+            item.push(&entry.file_name().into_string()?);
+            visit(&entry.path(), item, items)?;
+        }
     }
+    Ok(())
 }
 
 impl CollateralManager<FileSystemTree> {
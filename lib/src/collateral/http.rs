@@ -0,0 +1,246 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::{CollateralManager, CollateralTree, ItemPath, PVSS};
+use crate::Error;
+use crate::utils::Map;
+use std::ffi::OsString;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+/// Name of the file listing the items available on the server
+const INDEX: &str = "index.txt";
+
+/// Items available for each product, as listed in the index
+type Index = Map<PVSS, Vec<ItemPath>>;
+
+/// Provides access to a collateral tree published on an HTTP server.
+///
+/// The items are located at `URL/PRODUCT/VARIANT/STEPPING/SECURITY/crashlog/ITEM_PATH`, following
+/// the same structure as the [`super::FileSystemTree`]. The server must also provide an index at
+/// `URL/index.txt` that lists the paths of all the items relative to `URL`, one per line. Such an
+/// index can be generated from a local copy of the collateral tree as follows:
+///
+/// ```text
+/// $ cd collateral && find * -type f -path "*/crashlog/*" > index.txt
+/// ```
+///
+/// The index and the items are stored in a cache directory once downloaded. The cached copies
+/// are revalidated using their `ETag` when fetched again, and are used as is when the server
+/// reports an error. Once the server cannot be reached, or when the tree is
+/// [offline](HttpTree::offline), the cached copies are used without contacting the server. If
+/// nothing has been cached yet, the tree is then empty.
+///
+/// # Examples
+///
+/// ```no_run
+/// use intel_crashlog::prelude::*;
+/// use intel_crashlog::collateral::HttpTree;
+/// use std::path::Path;
+///
+/// let tree = HttpTree::new("https://example.com/collateral", Path::new("/tmp/collateral"));
+/// let cm = CollateralManager::new(tree).unwrap();
+/// ```
+pub struct HttpTree {
+    url: String,
+    cache: PathBuf,
+    offline: bool,
+    /// Set once the server failed to respond, such that the cache is used from then on
+    unreachable: AtomicBool,
+    agent: ureq::Agent,
+    index: OnceLock<Index>,
+}
+
+impl HttpTree {
+    /// Creates a collateral tree that downloads the items published at `url` into the `cache`
+    /// directory.
+    pub fn new(url: &str, cache: &Path) -> Self {
+        Self {
+            url: url.trim_end_matches('/').into(),
+            cache: cache.to_path_buf(),
+            offline: false,
+            unreachable: AtomicBool::new(false),
+            agent: ureq::AgentBuilder::new()
+                .timeout_connect(Duration::from_secs(5))
+                .timeout(Duration::from_secs(30))
+                .build(),
+            index: OnceLock::new(),
+        }
+    }
+
+    /// Only uses the items stored in the cache directory if `offline` is set, without contacting
+    /// the server.
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Checks whether the server must not be contacted anymore.
+    fn is_offline(&self) -> bool {
+        self.offline || self.unreachable.load(Ordering::Relaxed)
+    }
+
+    /// Returns the content of the file located at `path` relative to the URL of the tree, or
+    /// `None` if the server does not provide it.
+    fn fetch(&self, path: &str) -> Result<Option<Vec<u8>>, Error> {
+        let file = self.cache.join(path);
+        let mut etag_file = OsString::from(&file);
+        etag_file.push(".etag");
+        let etag_file = PathBuf::from(etag_file);
+
+        let cached = std::fs::read(&file).ok();
+        if self.is_offline() {
+            return Ok(cached);
+        }
+
+        let url = format!("{}/{path}", self.url);
+        let mut request = self.agent.get(&url);
+        if cached.is_some()
+            && let Ok(etag) = std::fs::read_to_string(&etag_file)
+        {
+            request = request.set("If-None-Match", etag.trim());
+        }
+
+        match request.call() {
+            Ok(response) if response.status() == 304 => {
+                log::debug!("Using cached copy of {url}");
+                Ok(cached)
+            }
+            Ok(response) => {
+                log::debug!("Downloading {url}");
+                let etag = response.header("ETag").map(String::from);
+                let mut data = Vec::new();
+                response.into_reader().read_to_end(&mut data)?;
+                Self::store(&file, &data, &etag_file, etag.as_deref())
+                    .unwrap_or_else(|err| log::warn!("Cannot cache {url}: {err}"));
+                Ok(Some(data))
+            }
+            Err(ureq::Error::Status(404, _)) => {
+                let _ = std::fs::remove_file(&file);
+                let _ = std::fs::remove_file(&etag_file);
+                Ok(None)
+            }
+            Err(err) => {
+                if let ureq::Error::Transport(_) = err {
+                    self.unreachable.store(true, Ordering::Relaxed);
+                }
+                if cached.is_none() {
+                    return Err(err.into());
+                }
+                log::warn!("{err}. Using the cached copy.");
+                Ok(cached)
+            }
+        }
+    }
+
+    fn store(file: &Path, data: &[u8], etag_file: &Path, etag: Option<&str>) -> io::Result<()> {
+        if let Some(parent) = file.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(file, data)?;
+        match etag {
+            Some(etag) => std::fs::write(etag_file, etag),
+            None => std::fs::remove_file(etag_file).or(Ok(())),
+        }
+    }
+
+    /// Returns the items listed in the index, fetching it on first use.
+    ///
+    /// The index is empty if the server cannot be reached and no copy of the index is cached.
+    fn index(&self) -> Result<&Index, Error> {
+        if let Some(index) = self.index.get() {
+            return Ok(index);
+        }
+
+        let data = match self.fetch(INDEX) {
+            Ok(Some(data)) => data,
+            Ok(None) | Err(_) if self.is_offline() => {
+                log::warn!(
+                    "No cached copy of {}/{INDEX} is available offline. Using an empty index.",
+                    self.url
+                );
+                Vec::new()
+            }
+            Ok(None) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("No collateral index found at {}/{INDEX}", self.url),
+                )
+                .into());
+            }
+            Err(err) => return Err(err),
+        };
+
+        let mut index = Index::default();
+        for line in std::str::from_utf8(&data)?.lines() {
+            let path: Vec<&str> = line.trim().split('/').collect();
+            match path.as_slice() {
+                [product, variant, stepping, security, "crashlog", item @ ..]
+                    if !item.is_empty() && !path.iter().any(|p| p.is_empty() || *p == "..") =>
+                {
+                    let pvss = PVSS {
+                        product: product.to_string(),
+                        variant: variant.to_string(),
+                        stepping: stepping.to_string(),
+                        security: security.to_string(),
+                    };
+                    index
+                        .entry(pvss)
+                        .or_default()
+                        .push(item.join("/").as_str().into());
+                }
+                [""] => (),
+                _ => log::warn!("Ignoring invalid path in collateral index: {line}"),
+            }
+        }
+
+        Ok(self.index.get_or_init(|| index))
+    }
+}
+
+impl CollateralTree for HttpTree {
+    fn get(&self, pvss: &PVSS, item: &ItemPath) -> Result<Vec<u8>, Error> {
+        let missing = || Error::MissingCollateral(pvss.clone(), item.clone());
+
+        if !self
+            .index()?
+            .get(pvss)
+            .is_some_and(|items| items.contains(item))
+        {
+            return Err(missing());
+        }
+
+        self.fetch(&format!("{pvss}/crashlog/{item}"))?
+            .ok_or_else(missing)
+    }
+
+    fn search(&self, item: &ItemPath) -> Result<Vec<PVSS>, Error> {
+        Ok(self
+            .index()?
+            .iter()
+            .filter(|(_, items)| items.contains(item))
+            .map(|(pvss, _)| pvss.clone())
+            .collect())
+    }
+
+    fn list(&self, path: &ItemPath) -> Result<Vec<(PVSS, ItemPath)>, Error> {
+        Ok(self
+            .index()?
+            .iter()
+            .flat_map(|(pvss, items)| items.iter().map(move |item| (pvss, item)))
+            .filter(|(_, item)| item.starts_with(path))
+            .map(|(pvss, item)| (pvss.clone(), item.clone()))
+            .collect())
+    }
+}
+
+impl CollateralManager<HttpTree> {
+    /// Creates a [`CollateralManager`] that uses a collateral tree published at `url`. The items
+    /// are downloaded into the `cache` directory.
+    pub fn http_tree(url: &str, cache: &Path) -> Result<Self, Error> {
+        Self::new(HttpTree::new(url, cache))
+    }
+}
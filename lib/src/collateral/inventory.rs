@@ -0,0 +1,155 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::{CollateralManager, CollateralTree, ItemPath, PVSS};
+use crate::Error;
+#[cfg(not(feature = "std"))]
+use alloc::{
+    fmt,
+    string::{String, ToString},
+    vec::Vec,
+};
+use serde::Serialize;
+#[cfg(feature = "std")]
+use std::fmt;
+
+/// Decode definitions available in the collateral tree for a type of record
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct DecodeDefinition {
+    /// Type of the record
+    pub record_type: String,
+    /// Type of the die that generates the record, if the decode definition is die-specific
+    pub die: Option<String>,
+    /// Revision of the record, or `all` if the decode definition applies to any revision
+    pub revision: String,
+}
+
+impl DecodeDefinition {
+    /// Extracts the decode definition from the path of a CSV file located in the collateral tree:
+    /// `decode-defs/TYPE/[DIE/]REVISION/FILE.csv`
+    fn from_path(path: &ItemPath) -> Option<Self> {
+        let elements: Vec<&str> = path.elements().collect();
+        let (record_type, die, revision, file) = match elements.as_slice() {
+            ["decode-defs", record_type, revision, file] => (record_type, None, revision, file),
+            ["decode-defs", record_type, die, revision, file] => {
+                (record_type, Some(die), revision, file)
+            }
+            _ => return None,
+        };
+
+        file.ends_with(".csv").then(|| DecodeDefinition {
+            record_type: record_type.to_string(),
+            die: die.map(|die| die.to_string()),
+            revision: revision.to_string(),
+        })
+    }
+
+    /// Returns the key used to sort the decode definitions by record type and revision. The
+    /// numeric revisions are ordered by value, followed by the other revisions such as `all`.
+    fn sort_key(&self) -> (&str, u32, &str, Option<&str>) {
+        (
+            &self.record_type,
+            self.revision.parse().unwrap_or(u32::MAX),
+            &self.revision,
+            self.die.as_deref(),
+        )
+    }
+}
+
+impl fmt::Display for DecodeDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} revision {}", self.record_type, self.revision)?;
+        if let Some(die) = &self.die {
+            write!(f, " ({die})")?;
+        }
+        Ok(())
+    }
+}
+
+/// Collateral items available in the collateral tree for a given [`PVSS`]
+#[derive(Debug, Serialize)]
+pub struct InventoryEntry {
+    /// Product, variant, stepping and security level of the items
+    pub pvss: PVSS,
+    /// Product ID defined in the target info of the product
+    pub product_id: Option<u32>,
+    /// Indicates if the target info of the product is defined for this [`PVSS`]
+    pub target_info: bool,
+    /// Decode definitions available for this [`PVSS`]
+    pub decode_definitions: Vec<DecodeDefinition>,
+}
+
+impl<T: CollateralTree> CollateralManager<T> {
+    /// Lists the collateral items available in the collateral tree, sorted by [`PVSS`].
+    ///
+    /// The decode definitions are discovered from the items located under the `decode-defs`
+    /// directory of the collateral tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let cm = CollateralManager::embedded_tree().unwrap();
+    /// let inventory = cm.inventory().unwrap();
+    /// let lnc = inventory
+    ///     .iter()
+    ///     .find(|entry| entry.pvss.to_string() == "LNC/all/all/green")
+    ///     .unwrap();
+    /// assert_eq!(lnc.product_id, Some(0x52));
+    /// assert_eq!(lnc.decode_definitions[0].to_string(), "PCORE revision 1");
+    /// ```
+    pub fn inventory(&self) -> Result<Vec<InventoryEntry>, Error> {
+        let mut entries: Vec<InventoryEntry> = Vec::new();
+
+        for pvss in self.tree.search(&ItemPath::new(["target_info.json"]))? {
+            self.entry(&mut entries, pvss).target_info = true;
+        }
+
+        for (pvss, path) in self.tree.list(&ItemPath::new(["decode-defs"]))? {
+            if let Some(decode_definition) = DecodeDefinition::from_path(&path) {
+                self.entry(&mut entries, pvss)
+                    .decode_definitions
+                    .push(decode_definition);
+            }
+        }
+
+        for entry in entries.iter_mut() {
+            entry
+                .decode_definitions
+                .sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
+            entry.decode_definitions.dedup();
+        }
+        entries.sort_by(|a, b| a.pvss.cmp(&b.pvss));
+        Ok(entries)
+    }
+
+    /// Returns the entry of the `entries` associated to the `pvss`, creating it if needed.
+    fn entry<'a>(
+        &self,
+        entries: &'a mut Vec<InventoryEntry>,
+        pvss: PVSS,
+    ) -> &'a mut InventoryEntry {
+        if let Some(i) = entries.iter().position(|entry| entry.pvss == pvss) {
+            return &mut entries[i];
+        }
+
+        let product_id = self
+            .target_info
+            .iter()
+            .filter(|(_, target_info)| {
+                target_info.product == pvss.product
+                    && (target_info.variant == pvss.variant || target_info.variant == "all")
+            })
+            .map(|(product_id, _)| *product_id)
+            .min();
+
+        entries.push(InventoryEntry {
+            pvss,
+            product_id,
+            target_info: false,
+            decode_definitions: Vec::new(),
+        });
+        entries.last_mut().unwrap()
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::{CollateralManager, CollateralTree, ItemPath, PVSS};
+use crate::Error;
+use crate::record::Layout;
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, vec::Vec};
+
+/// Collateral tree that can be stored in a [LayeredTree]
+type Layer = Box<dyn CollateralTree + Send + Sync>;
+
+/// Stacks several collateral trees on top of each other.
+///
+/// The items are fetched from the tree with the highest priority that defines them. Trees with
+/// the same priority are looked up in the order they have been added. The [`PVSS`] returned by
+/// [CollateralTree::search] are merged across all the trees, such that the products defined in
+/// any of the trees are available.
+///
+/// # Examples
+///
+/// ```
+/// use intel_crashlog::prelude::*;
+/// use intel_crashlog::collateral::{EmbeddedTree, FileSystemTree, LayeredTree};
+/// use std::path::Path;
+///
+/// let mut tree = LayeredTree::default();
+/// tree.push(0, EmbeddedTree::new());
+/// tree.push(1, FileSystemTree::new(Path::new("tests/collateral")));
+///
+/// let cm = CollateralManager::layered_tree(tree).unwrap();
+/// assert_eq!(cm.target_info.get(&0x052).unwrap().product, "LNC");
+/// assert_eq!(cm.target_info.get(&0x07A).unwrap().product, "XYZ");
+/// ```
+#[derive(Default)]
+pub struct LayeredTree {
+    /// Collateral trees sorted by decreasing priority
+    layers: Vec<(i32, Layer)>,
+}
+
+impl LayeredTree {
+    /// Adds a collateral `tree` to the stack with the given `priority`.
+    pub fn push(&mut self, priority: i32, tree: impl CollateralTree + Send + Sync + 'static) {
+        let position = self
+            .layers
+            .iter()
+            .position(|(p, _)| *p < priority)
+            .unwrap_or(self.layers.len());
+        self.layers.insert(position, (priority, Box::new(tree)));
+    }
+
+    /// Returns the number of trees in the stack.
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Checks whether the stack contains no trees.
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Returns the first item successfully returned by `get`, going through the trees by
+    /// decreasing priority.
+    fn find<I>(
+        &self,
+        pvss: &PVSS,
+        item: &ItemPath,
+        get: impl Fn(&Layer) -> Result<I, Error>,
+    ) -> Result<I, Error> {
+        for (priority, tree) in self.layers.iter() {
+            match get(tree) {
+                Ok(item) => return Ok(item),
+                Err(Error::MissingCollateral(..)) => (),
+                Err(err) => log::warn!("Cannot fetch {item} from tree {priority}: {err}"),
+            }
+        }
+        Err(Error::MissingCollateral(pvss.clone(), item.clone()))
+    }
+}
+
+impl CollateralTree for LayeredTree {
+    fn get(&self, pvss: &PVSS, item: &ItemPath) -> Result<Vec<u8>, Error> {
+        self.find(pvss, item, |tree| tree.get(pvss, item))
+    }
+
+    /// Returns the [`PVSS`] found in all the trees, ordered by increasing priority of the tree
+    /// that defines them.
+    ///
+    /// The trees that cannot be searched are skipped. An error is returned only if none of the
+    /// trees can be searched.
+    fn search(&self, item: &ItemPath) -> Result<Vec<PVSS>, Error> {
+        let mut hits: Vec<PVSS> = Vec::new();
+        let mut error = None;
+        let mut searched = false;
+        for (priority, tree) in self.layers.iter().rev() {
+            match tree.search(item) {
+                Ok(found) => {
+                    searched = true;
+                    for pvss in found {
+                        hits.retain(|hit| *hit != pvss);
+                        hits.push(pvss);
+                    }
+                }
+                Err(err) => {
+                    log::warn!("Cannot search {item} in tree {priority}: {err}");
+                    error = Some(err);
+                }
+            }
+        }
+
+        match error {
+            Some(err) if !searched => Err(err),
+            _ => Ok(hits),
+        }
+    }
+
+    /// Returns the items found in all the trees, ordered by increasing priority of the tree that
+    /// defines them.
+    ///
+    /// The trees that cannot be listed are skipped. An error is returned only if none of the trees
+    /// can be listed.
+    fn list(&self, path: &ItemPath) -> Result<Vec<(PVSS, ItemPath)>, Error> {
+        let mut items: Vec<(PVSS, ItemPath)> = Vec::new();
+        let mut error = None;
+        let mut listed = false;
+        for (priority, tree) in self.layers.iter().rev() {
+            match tree.list(path) {
+                Ok(found) => {
+                    listed = true;
+                    for item in found {
+                        items.retain(|listed| *listed != item);
+                        items.push(item);
+                    }
+                }
+                Err(err) => {
+                    log::warn!("Cannot list {path} in tree {priority}: {err}");
+                    error = Some(err);
+                }
+            }
+        }
+
+        match error {
+            Some(err) if !listed => Err(err),
+            _ => Ok(items),
+        }
+    }
+
+    fn get_layout(&self, pvss: &PVSS, item: &ItemPath) -> Result<Layout, Error> {
+        self.find(pvss, item, |tree| tree.get_layout(pvss, item))
+    }
+}
+
+impl CollateralManager<LayeredTree> {
+    /// Creates a [`CollateralManager`] that uses a stack of collateral trees.
+    ///
+    /// When several trees define the target info of the same product, the one defined in the
+    /// tree with the highest priority is used.
+    pub fn layered_tree(tree: LayeredTree) -> Result<Self, Error> {
+        Self::new(tree)
+    }
+}
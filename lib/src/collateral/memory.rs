@@ -0,0 +1,122 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::{CollateralManager, CollateralTree, ItemPath, PVSS};
+use crate::Error;
+use crate::utils::Map;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Provides access to a collateral tree stored in memory.
+///
+/// The items are registered at runtime, which allows the decode definitions to be supplied
+/// programmatically without any file system access.
+///
+/// # Examples
+///
+/// ```
+/// use intel_crashlog::prelude::*;
+/// use intel_crashlog::collateral::{MemoryTree, PVSS};
+///
+/// let pvss: PVSS = "XYZ/all/all/all".parse().unwrap();
+/// let mut tree = MemoryTree::new();
+/// tree.insert(
+///     pvss.clone(),
+///     "target_info.json",
+///     r#"{"product": "XYZ", "product_id": "0x7a"}"#,
+/// );
+///
+/// let mut cm = CollateralManager::memory_tree(tree).unwrap();
+/// assert_eq!(cm.target_info.get(&0x7a).unwrap().product, "XYZ");
+///
+/// cm.update_tree(|tree| {
+///     let layout = "name;offset;size\nmca;0;64\nmca.foo;32;8";
+///     tree.insert(pvss.clone(), "decode-defs/MCA/1/layout.csv", layout);
+/// })
+/// .unwrap();
+///
+/// let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+/// let crashlog = CrashLog::from_slice(&data).unwrap();
+/// let root = crashlog.decode(&cm);
+/// assert!(root.get_by_path("mca.foo").is_some());
+/// assert!(root.get_by_path("mca.hdr").is_none());
+/// ```
+#[derive(Clone, Default)]
+pub struct MemoryTree {
+    registry: Map<PVSS, Map<ItemPath, Vec<u8>>>,
+}
+
+impl MemoryTree {
+    /// Creates an empty collateral tree.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers an item in the collateral tree. Returns the previous content of the item, if
+    /// any.
+    pub fn insert(
+        &mut self,
+        pvss: PVSS,
+        path: impl Into<ItemPath>,
+        content: impl Into<Vec<u8>>,
+    ) -> Option<Vec<u8>> {
+        self.registry
+            .entry(pvss)
+            .or_default()
+            .insert(path.into(), content.into())
+    }
+
+    /// Removes an item from the collateral tree. Returns the content of the item, if any.
+    pub fn remove(&mut self, pvss: &PVSS, path: &ItemPath) -> Option<Vec<u8>> {
+        let items = self.registry.get_mut(pvss)?;
+        let item = items.remove(path);
+        if items.is_empty() {
+            self.registry.remove(pvss);
+        }
+        item
+    }
+
+    /// Checks whether the collateral tree contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.registry.is_empty()
+    }
+}
+
+impl CollateralTree for MemoryTree {
+    fn get(&self, pvss: &PVSS, item: &ItemPath) -> Result<Vec<u8>, Error> {
+        self.registry
+            .get(pvss)
+            .and_then(|items| items.get(item))
+            .cloned()
+            .ok_or_else(|| Error::MissingCollateral(pvss.clone(), item.clone()))
+    }
+
+    fn search(&self, item: &ItemPath) -> Result<Vec<PVSS>, Error> {
+        Ok(self
+            .registry
+            .iter()
+            .filter(|(_, items)| items.contains_key(item))
+            .map(|(pvss, _)| pvss.clone())
+            .collect())
+    }
+
+    fn list(&self, path: &ItemPath) -> Result<Vec<(PVSS, ItemPath)>, Error> {
+        Ok(self
+            .registry
+            .iter()
+            .flat_map(|(pvss, items)| items.keys().map(move |item| (pvss, item)))
+            .filter(|(_, item)| item.starts_with(path))
+            .map(|(pvss, item)| (pvss.clone(), item.clone()))
+            .collect())
+    }
+}
+
+impl CollateralManager<MemoryTree> {
+    /// Creates a [`CollateralManager`] that uses a collateral tree stored in memory.
+    ///
+    /// Items can be registered after the creation of the collateral manager using
+    /// [`CollateralManager::update_tree`].
+    pub fn memory_tree(tree: MemoryTree) -> Result<Self, Error> {
+        Self::new(tree)
+    }
+}
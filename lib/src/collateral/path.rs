@@ -18,6 +18,16 @@ impl ItemPath {
     pub(crate) fn push(&mut self, element: &str) {
         self.0.push(element.into())
     }
+
+    /// Returns the elements of the path.
+    pub fn elements(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(String::as_str)
+    }
+
+    /// Checks whether the path is located under the `parent` path.
+    pub fn starts_with(&self, parent: &ItemPath) -> bool {
+        self.0.starts_with(&parent.0)
+    }
 }
 
 #[cfg(feature = "std")]
@@ -1,15 +1,17 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
+use crate::Error;
 #[cfg(not(feature = "std"))]
-use alloc::{fmt, string::String};
+use alloc::{fmt, str::FromStr, string::String};
+use serde::Serialize;
 #[cfg(feature = "std")]
-use std::{fmt, path::PathBuf};
+use std::{fmt, path::PathBuf, str::FromStr};
 
 /// A tuple of 4 strings that uniquely identifies a product.
 ///
 /// Undefined elements of the tuples must be set to "all". For example: `XYZ/all/all/all`
-#[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize)]
 pub struct PVSS {
     /// Product TLA
     pub product: String,
@@ -42,6 +44,26 @@ impl From<&PVSS> for PathBuf {
     }
 }
 
+impl FromStr for PVSS {
+    type Err = Error;
+
+    /// Parses a [`PVSS`] formatted as `PRODUCT/VARIANT/STEPPING/SECURITY`.
+    fn from_str(pvss: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidPVSS(pvss.into());
+        let mut elements = pvss.split('/').map(String::from);
+        let parsed = PVSS {
+            product: elements.next().ok_or_else(invalid)?,
+            variant: elements.next().ok_or_else(invalid)?,
+            stepping: elements.next().ok_or_else(invalid)?,
+            security: elements.next().ok_or_else(invalid)?,
+        };
+        if elements.next().is_some() {
+            return Err(invalid());
+        }
+        Ok(parsed)
+    }
+}
+
 impl fmt::Display for PVSS {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -0,0 +1,238 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+//! Binary containers in which the Crash Log records are reported by the platform.
+
+#[cfg(test)]
+mod tests;
+
+use crate::CrashLog;
+use crate::bert::{Berr, Bert, berr};
+use crate::cper::{self, Cper, CperSection};
+use crate::metadata::{RecordSource, SectionSource};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use core::mem;
+#[cfg(feature = "std")]
+use std::mem;
+use uguid::Guid;
+
+/// Magic number prepended to the Boot Error Regions that are not preceded by their [Bert].
+const BERR_MAGIC: &[u8] = b"BERR";
+/// Signatures found at the beginning of the supported containers
+const SIGNATURES: [&[u8]; 3] = [b"BERT", BERR_MAGIC, b"CPER"];
+
+/// Type of the binary container used to export a [CrashLog].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerType {
+    /// ACPI Boot Error Record Table followed by its Boot Error Region
+    Bert,
+    /// Common Platform Error Record
+    Cper,
+}
+
+/// Parsed structure of the binary a [CrashLog] has been extracted from.
+///
+/// The container keeps all the headers and sections found in the original binary, including the
+/// ones that are not related to Crash Log, such that it can be written back unmodified.
+#[derive(Clone)]
+pub enum Container {
+    /// ACPI Boot Error Record Table followed by its Boot Error Region
+    Bert { table: Bert, region: Berr },
+    /// Boot Error Region prefixed by the `BERR` magic number
+    Berr(Berr),
+    /// Common Platform Error Record
+    Cper(Cper),
+}
+
+impl Container {
+    /// Builds a new container of the given type from the regions and the metadata of `crashlog`.
+    pub fn from_crashlog(crashlog: &CrashLog, container_type: ContainerType) -> Self {
+        match container_type {
+            ContainerType::Bert => {
+                let region = Berr::from_crashlog(crashlog);
+                let table = Bert::new(0, region.to_bytes().len() as u32);
+                Container::Bert { table, region }
+            }
+            ContainerType::Cper => Container::Cper(Cper::from_crashlog(crashlog)),
+        }
+    }
+
+    /// Returns the type of the container.
+    pub fn container_type(&self) -> ContainerType {
+        match self {
+            Container::Bert { .. } | Container::Berr(_) => ContainerType::Bert,
+            Container::Cper(_) => ContainerType::Cper,
+        }
+    }
+
+    /// Returns a copy of the container if it is of the given type. A Boot Error Region that is
+    /// not preceded by its [Bert] gets a new table.
+    fn with_type(&self, container_type: ContainerType) -> Option<Self> {
+        if self.container_type() != container_type {
+            return None;
+        }
+
+        match self {
+            Container::Berr(region) => Some(Container::Bert {
+                table: Bert::new(0, region.to_bytes().len() as u32),
+                region: region.clone(),
+            }),
+            container => Some(container.clone()),
+        }
+    }
+
+    /// Exports the [CrashLog] in a container of the given type.
+    ///
+    /// The original container of the [CrashLog] is reused if it is of the requested type.
+    /// Otherwise, a new container is built from its regions and its metadata.
+    pub fn export(crashlog: &CrashLog, container_type: ContainerType) -> Self {
+        crashlog
+            .container
+            .as_ref()
+            .and_then(|container| container.with_type(container_type))
+            .unwrap_or_else(|| Container::from_crashlog(crashlog, container_type))
+    }
+
+    /// Decodes the container stored in a byte slice.
+    ///
+    /// Returns `None` if the slice does not start with a BERT, a Boot Error Region prefixed by
+    /// the `BERR` magic number, or a CPER.
+    pub fn from_slice(s: &[u8]) -> Option<Self> {
+        if let Some(region) = s.strip_prefix(BERR_MAGIC) {
+            Berr::from_slice(region).map(Container::Berr)
+        } else if s.starts_with(b"BERT") {
+            let table = Bert::from_slice(s)?;
+            let length = match table.region_length {
+                0 => None,
+                length => Some(length as usize),
+            };
+            Some(Container::Bert {
+                region: Berr::from_region(s.get(mem::size_of::<Bert>()..)?, length)?,
+                table,
+            })
+        } else {
+            Cper::from_slice(s).map(Container::Cper)
+        }
+    }
+
+    /// Returns the size of the container as declared in its headers.
+    fn declared_size(&self) -> usize {
+        match self {
+            Container::Bert { table, region } => {
+                mem::size_of::<Bert>().saturating_add(match table.region_length {
+                    0 => region.declared_size(),
+                    length => length as usize,
+                })
+            }
+            Container::Berr(region) => BERR_MAGIC.len().saturating_add(region.declared_size()),
+            Container::Cper(cper) => cper.record_header.record_length as usize,
+        }
+    }
+
+    /// Appends an error section to the container.
+    pub(crate) fn push_section(&mut self, section_type: Guid, section: CperSection) {
+        match self {
+            Container::Bert { table, region } => {
+                region.push_section(section_type, section);
+                table.region_length = region.to_bytes().len() as u32;
+            }
+            Container::Berr(region) => region.push_section(section_type, section),
+            Container::Cper(cper) => cper.push_section(section_type, section),
+        }
+    }
+
+    /// Exports the container as a sequence of bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Container::Bert { table, region } => {
+                let mut bytes = table.to_bytes();
+                bytes.append(&mut region.to_bytes());
+                bytes
+            }
+            Container::Berr(region) => {
+                let mut bytes = Vec::from(BERR_MAGIC);
+                bytes.append(&mut region.to_bytes());
+                bytes
+            }
+            Container::Cper(cper) => cper.to_bytes(),
+        }
+    }
+}
+
+/// Error section located in a container
+pub(crate) struct SectionRef<'a> {
+    pub section_type: Guid,
+    pub source: SectionSource,
+    pub data: &'a [u8],
+}
+
+/// Locates the error sections of the container stored at the beginning of `s` without copying
+/// their content.
+pub(crate) fn sections_from_slice(s: &[u8]) -> Option<(RecordSource, Vec<SectionRef<'_>>)> {
+    let region = if let Some(region) = s.strip_prefix(BERR_MAGIC) {
+        region
+    } else if s.starts_with(b"BERT") {
+        Bert::from_slice(s)?;
+        s.get(mem::size_of::<Bert>()..)?
+    } else {
+        let (header, sections) = cper::sections_from_slice(s)?;
+        let sections = sections
+            .into_iter()
+            .map(|(descriptor, data)| SectionRef {
+                section_type: descriptor.section_type,
+                source: SectionSource::from(&descriptor),
+                data,
+            })
+            .collect();
+        return Some((RecordSource::from(&header), sections));
+    };
+
+    let (header, entries) = berr::entries_from_slice(region)?;
+    let sections = entries
+        .into_iter()
+        .map(|(entry_header, data)| SectionRef {
+            section_type: entry_header.section,
+            source: SectionSource::from(&entry_header),
+            data,
+        })
+        .collect();
+    Some((RecordSource::from(&header), sections))
+}
+
+/// Splits a buffer made of consecutive containers.
+///
+/// The bytes located between two containers are kept with the first one. Returns an empty
+/// vector if the buffer does not start with a container.
+pub(crate) fn split(s: &[u8]) -> Vec<&[u8]> {
+    let mut starts = Vec::new();
+    let mut cursor = 0;
+
+    loop {
+        match s.get(cursor..).and_then(Container::from_slice) {
+            Some(container) => {
+                starts.push(cursor);
+                cursor = cursor.saturating_add(container.declared_size().max(SIGNATURES[0].len()));
+            }
+            None if starts.is_empty() => return Vec::new(),
+            None => cursor += SIGNATURES[0].len(),
+        }
+
+        let next = s.get(cursor..).and_then(|s| {
+            s.windows(SIGNATURES[0].len())
+                .position(|window| SIGNATURES.contains(&window))
+        });
+        match next {
+            Some(offset) => cursor += offset,
+            None => break,
+        }
+    }
+
+    log::debug!("Found {} containers", starts.len());
+    starts
+        .iter()
+        .enumerate()
+        .map(|(i, start)| &s[*start..starts.get(i + 1).copied().unwrap_or(s.len())])
+        .collect()
+}
@@ -0,0 +1,155 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::{Container, ContainerType};
+use crate::CrashLog;
+use crate::bert::Bert;
+
+#[test]
+fn lossless() {
+    for sample in [
+        "tests/samples/dummy.bert",
+        "tests/samples/cper_sections.bert",
+        "tests/samples/cper.whea",
+    ] {
+        let data = std::fs::read(sample).unwrap();
+        let container = Container::from_slice(&data).unwrap();
+        assert_eq!(container.to_bytes(), data, "{sample}");
+
+        let crashlog = CrashLog::from_slice(&data).unwrap();
+        assert_eq!(crashlog.to_bytes(), data, "{sample}");
+    }
+}
+
+#[test]
+fn berr_magic() {
+    let bert = std::fs::read("tests/samples/dummy.bert").unwrap();
+    let mut data = Vec::from(b"BERR");
+    data.extend_from_slice(&bert[48..]);
+
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    assert!(matches!(crashlog.container, Some(Container::Berr(_))));
+    assert_eq!(crashlog.regions.len(), 2);
+    assert_eq!(crashlog.to_bytes(), data);
+}
+
+#[test]
+fn unpack() {
+    let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    assert!(crashlog.container.is_none());
+    assert_eq!(crashlog.regions[0].to_bytes(), data);
+}
+
+#[test]
+fn synthesized() {
+    let bert = std::fs::read("tests/samples/dummy.bert").unwrap();
+    let mut crashlog = CrashLog::from_slice(&bert).unwrap();
+    crashlog.container = None;
+
+    let bytes = crashlog.to_bytes();
+    let table = Bert::from_slice(&bytes).unwrap();
+    let length = table.header.length;
+    assert_eq!(length, 48);
+    assert_eq!(
+        bytes[..48]
+            .iter()
+            .fold(0u8, |acc, byte| acc.wrapping_add(*byte)),
+        0
+    );
+    let region_length = table.region_length;
+    assert_eq!(region_length as usize, bytes.len() - 48);
+
+    let crashlog = CrashLog::from_slice(&bytes).unwrap();
+    assert_eq!(crashlog.regions.len(), 2);
+}
+
+#[test]
+fn export_cper() {
+    let bert = std::fs::read("tests/samples/cper_sections.bert").unwrap();
+    let crashlog = CrashLog::from_slice(&bert).unwrap();
+    assert_eq!(crashlog.export(ContainerType::Bert), bert);
+
+    let bytes = crashlog.export(ContainerType::Cper);
+    let cper = crate::cper::Cper::from_slice(&bytes).unwrap();
+    assert_eq!(cper.record_header.record_length as usize, bytes.len());
+    assert_eq!(cper.sections.len(), 2 + 3);
+    assert_eq!(cper.sections[0].descriptor.flags, 1);
+    assert_eq!(cper.sections[0].descriptor.section_offset, 128 + 5 * 72);
+
+    let converted = CrashLog::from_slice(&bytes).unwrap();
+    assert!(matches!(converted.container, Some(Container::Cper(_))));
+    assert_eq!(converted.regions.len(), crashlog.regions.len());
+    assert_eq!(converted.cper_sections.len(), 3);
+    assert_eq!(
+        converted.regions[0].source.as_ref().unwrap().severity,
+        crashlog.regions[0].source.as_ref().unwrap().severity
+    );
+    assert_eq!(converted.export(ContainerType::Cper), bytes);
+}
+
+#[test]
+fn export_bert() {
+    let data = std::fs::read("tests/samples/cper.whea").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+
+    let bytes = crashlog.export(ContainerType::Bert);
+    let converted = CrashLog::from_slice(&bytes).unwrap();
+    assert!(matches!(converted.container, Some(Container::Bert { .. })));
+    assert_eq!(converted.regions.len(), crashlog.regions.len());
+    assert_eq!(
+        converted.metadata.source.as_ref().unwrap().severity,
+        crashlog.metadata.source.as_ref().unwrap().severity
+    );
+}
+
+#[test]
+fn export_raw() {
+    let data = std::fs::read("tests/samples/three_strike_timeout_box.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    assert!(crashlog.regions.len() > 1);
+
+    let regions = crashlog.export_regions();
+    assert_eq!(regions, vec![data]);
+    let converted = CrashLog::from_slice(&regions[0]).unwrap();
+    assert_eq!(converted.regions.len(), crashlog.regions.len());
+
+    let bert = CrashLog::from_slice(&crashlog.export(ContainerType::Bert)).unwrap();
+    assert_eq!(bert.regions.len(), crashlog.regions.len());
+
+    let data = std::fs::read("tests/samples/dummy.bert").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    let regions = crashlog.export_regions();
+    assert_eq!(regions.len(), 2);
+    for (region, bytes) in crashlog.regions.iter().zip(regions) {
+        let converted = CrashLog::from_slice(&bytes).unwrap();
+        assert_eq!(converted.regions.len(), 1);
+        assert_eq!(converted.regions[0].records.len(), region.records.len());
+    }
+}
+
+#[test]
+fn concatenated() {
+    let cper = std::fs::read("tests/samples/cper.whea").unwrap();
+    let bert = std::fs::read("tests/samples/dummy.bert").unwrap();
+
+    let mut data = cper.clone();
+    data.extend_from_slice(&[0; 16]);
+    data.extend_from_slice(&bert);
+    data.extend_from_slice(&cper);
+
+    let slices = super::split(&data);
+    assert_eq!(slices.len(), 3);
+    assert_eq!(slices[1], bert);
+    assert_eq!(slices[2], cper);
+
+    let crashlogs = CrashLog::all_from_slice(&data).unwrap();
+    assert_eq!(crashlogs.len(), 3);
+    assert_eq!(crashlogs[0].to_bytes(), cper);
+    assert_eq!(crashlogs[1].regions.len(), 2);
+    assert_eq!(crashlogs[2].to_bytes(), cper);
+
+    let raw = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    assert!(super::split(&raw).is_empty());
+    assert_eq!(CrashLog::all_from_slice(&raw).unwrap().len(), 1);
+}
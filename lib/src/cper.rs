@@ -1,49 +1,127 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
+//! Common Platform Error Record (UEFI 2.10 - Appendix N)
+
 #![allow(dead_code)]
 
 pub mod fer;
+pub mod memory;
+pub mod pcie;
+pub mod processor;
 #[cfg(test)]
 mod tests;
 
+use crate::CrashLog;
+use crate::metadata::{RecordSource, SectionSource, Time, parse_fru_text};
+use crate::node::Node;
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 use fer::FirmwareErrorRecord;
+use memory::{MEMORY_ERROR_SECTION_GUID, MemoryErrorSection};
+use pcie::{PCIE_ERROR_SECTION_GUID, PcieErrorSection};
+use processor::{PROCESSOR_ERROR_SECTION_GUID, ProcessorErrorSection};
 use uguid::Guid;
 
 pub const FW_ERROR_RECORD_GUID: Guid = uguid::guid!("81212a96-09ed-4996-9471-8d729c8e69ed");
-const RECORD_HEADER_SIZE: usize = 128;
-const SECTION_DESCRIPTOR_SIZE: usize = 72;
+/// Notification type of the errors reported at boot time
+pub const BOOT_NOTIFICATION_TYPE: Guid = uguid::guid!("3d61a466-ab40-409a-a698-f362d464b38f");
+pub(crate) const RECORD_HEADER_SIZE: usize = 128;
+pub(crate) const SECTION_DESCRIPTOR_SIZE: usize = 72;
+
+/// Decodes the little-endian fields of a section into `node`. Each field is described by its
+/// path relative to `node`, its offset and its size in bytes.
+fn decode_fields(node: &mut Node, data: &[u8], fields: &[(&str, usize, usize)]) {
+    for (path, offset, size) in fields {
+        let Some(bytes) = data.get(*offset..offset + size) else {
+            continue;
+        };
+        let mut value = [0; 8];
+        value[..*size].copy_from_slice(bytes);
+
+        let (parent, name) = match path.rsplit_once('.') {
+            Some((parent, name)) => (node.create_hierarchy(parent), name),
+            None => (&mut *node, *path),
+        };
+        parent.add(Node::field(name, u64::from_le_bytes(value)));
+    }
+}
 
+#[derive(Clone)]
 pub enum CperSection {
     FirmwareErrorRecord(FirmwareErrorRecord),
+    Processor(ProcessorErrorSection),
+    Memory(MemoryErrorSection),
+    Pcie(PcieErrorSection),
     Unknown(Vec<u8>),
 }
 
 impl CperSection {
-    pub(super) fn from_slice(guid: uguid::Guid, s: &[u8]) -> Option<CperSection> {
-        Some(match guid {
+    pub(crate) fn from_slice(guid: uguid::Guid, s: &[u8]) -> Option<CperSection> {
+        let section = match guid {
             FW_ERROR_RECORD_GUID => {
-                CperSection::FirmwareErrorRecord(fer::FirmwareErrorRecord::from_slice(s)?)
+                return Some(CperSection::FirmwareErrorRecord(
+                    fer::FirmwareErrorRecord::from_slice(s)?,
+                ));
             }
-            _ => CperSection::Unknown(Vec::from(s)),
-        })
+            PROCESSOR_ERROR_SECTION_GUID => {
+                ProcessorErrorSection::from_slice(s).map(CperSection::Processor)
+            }
+            MEMORY_ERROR_SECTION_GUID => MemoryErrorSection::from_slice(s).map(CperSection::Memory),
+            PCIE_ERROR_SECTION_GUID => PcieErrorSection::from_slice(s).map(CperSection::Pcie),
+            _ => None,
+        };
+
+        Some(section.unwrap_or_else(|| CperSection::Unknown(Vec::from(s))))
     }
 
     pub(super) fn to_bytes(&self) -> Vec<u8> {
         match self {
             CperSection::FirmwareErrorRecord(fer) => fer.to_bytes(),
+            CperSection::Processor(section) => section.data.clone(),
+            CperSection::Memory(section) => section.data.clone(),
+            CperSection::Pcie(section) => section.data.clone(),
             CperSection::Unknown(data) => data.clone(),
         }
     }
+
+    /// Returns the type of the standard error sections.
+    pub fn section_type(&self) -> Option<Guid> {
+        match self {
+            CperSection::FirmwareErrorRecord(_) => Some(FW_ERROR_RECORD_GUID),
+            CperSection::Processor(_) => Some(PROCESSOR_ERROR_SECTION_GUID),
+            CperSection::Memory(_) => Some(MEMORY_ERROR_SECTION_GUID),
+            CperSection::Pcie(_) => Some(PCIE_ERROR_SECTION_GUID),
+            CperSection::Unknown(_) => None,
+        }
+    }
+
+    /// Returns true if the section is a standard processor, memory, or PCIe error section.
+    pub fn is_error_section(&self) -> bool {
+        matches!(
+            self,
+            CperSection::Processor(_) | CperSection::Memory(_) | CperSection::Pcie(_)
+        )
+    }
+
+    /// Returns the register tree representation of the standard error sections.
+    pub fn decode(&self) -> Option<Node> {
+        match self {
+            CperSection::Processor(section) => Some(Node::from(section)),
+            CperSection::Memory(section) => Some(Node::from(section)),
+            CperSection::Pcie(section) => Some(Node::from(section)),
+            _ => None,
+        }
+    }
 }
 
+#[derive(Clone)]
 pub struct Revision {
     pub minor: u8,
     pub major: u8,
 }
 
+#[derive(Clone)]
 pub struct CperSectionDescriptor {
     pub section_offset: u32,
     pub section_length: u32,
@@ -58,7 +136,7 @@ pub struct CperSectionDescriptor {
 }
 
 impl CperSectionDescriptor {
-    fn from_slice(s: &[u8]) -> Option<Self> {
+    pub(crate) fn from_slice(s: &[u8]) -> Option<Self> {
         Some(CperSectionDescriptor {
             section_offset: u32::from_le_bytes(s.get(0..4)?.try_into().ok()?),
             section_length: u32::from_le_bytes(s.get(4..8)?.try_into().ok()?),
@@ -75,13 +153,78 @@ impl CperSectionDescriptor {
             fru_text: s.get(52..72)?.try_into().ok()?,
         })
     }
+
+    /// Builds the descriptor of a section of type `section_type`. The fields captured in `source`
+    /// are restored. The offset and the length of the section are computed when the record is
+    /// exported.
+    pub fn new(section_type: Guid, source: Option<&SectionSource>) -> Self {
+        let mut descriptor = CperSectionDescriptor {
+            section_offset: 0,
+            section_length: 0,
+            revision: Revision { minor: 0, major: 3 },
+            validation_bits: 0,
+            reserved: 0,
+            flags: 0,
+            section_type,
+            fru_id: Guid::ZERO,
+            section_severity: 0,
+            fru_text: [0; 20],
+        };
+
+        let Some(source) = source else {
+            return descriptor;
+        };
+
+        descriptor.section_severity = source.severity;
+        if let Some(fru_id) = source.fru_id {
+            descriptor.validation_bits |= 1;
+            descriptor.fru_id = fru_id;
+        }
+        if let Some(fru_text) = source.fru_text.as_ref() {
+            descriptor.validation_bits |= 2;
+            let len = fru_text.len().min(descriptor.fru_text.len());
+            descriptor.fru_text[..len].copy_from_slice(&fru_text.as_bytes()[..len]);
+        }
+        descriptor
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(SECTION_DESCRIPTOR_SIZE);
+        bytes.extend_from_slice(&self.section_offset.to_le_bytes());
+        bytes.extend_from_slice(&self.section_length.to_le_bytes());
+        bytes.push(self.revision.minor);
+        bytes.push(self.revision.major);
+        bytes.push(self.validation_bits);
+        bytes.push(self.reserved);
+        bytes.extend_from_slice(&self.flags.to_le_bytes());
+        bytes.extend_from_slice(&self.section_type.to_bytes());
+        bytes.extend_from_slice(&self.fru_id.to_bytes());
+        bytes.extend_from_slice(&self.section_severity.to_le_bytes());
+        bytes.extend_from_slice(&self.fru_text);
+        bytes
+    }
+}
+
+impl From<&CperSectionDescriptor> for SectionSource {
+    fn from(descriptor: &CperSectionDescriptor) -> Self {
+        SectionSource {
+            severity: descriptor.section_severity,
+            fru_id: (descriptor.validation_bits & 1 != 0).then_some(descriptor.fru_id),
+            fru_text: (descriptor.validation_bits & 2 != 0)
+                .then(|| parse_fru_text(&descriptor.fru_text))
+                .flatten(),
+            timestamp: None,
+        }
+    }
 }
 
+#[derive(Clone)]
 pub struct Section {
     pub descriptor: CperSectionDescriptor,
     pub section: CperSection,
 }
 
+#[derive(Clone)]
 pub struct CperHeader {
     pub signature_start: u32,
     pub revision: Revision,
@@ -129,30 +272,165 @@ impl CperHeader {
             None
         }
     }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(RECORD_HEADER_SIZE);
+        bytes.extend_from_slice(&self.signature_start.to_le_bytes());
+        bytes.push(self.revision.minor);
+        bytes.push(self.revision.major);
+        bytes.extend_from_slice(&self.signature_end.to_le_bytes());
+        bytes.extend_from_slice(&self.section_count.to_le_bytes());
+        bytes.extend_from_slice(&self.error_severity.to_le_bytes());
+        bytes.extend_from_slice(&self.validation_bits.to_le_bytes());
+        bytes.extend_from_slice(&self.record_length.to_le_bytes());
+        bytes.extend_from_slice(&self.timestamp.to_le_bytes());
+        bytes.extend_from_slice(&self.platform_id.to_bytes());
+        bytes.extend_from_slice(&self.partition_id.to_bytes());
+        bytes.extend_from_slice(&self.creator_id.to_bytes());
+        bytes.extend_from_slice(&self.notification_type.to_bytes());
+        bytes.extend_from_slice(&self.record_id.to_le_bytes());
+        bytes.extend_from_slice(&self.flags.to_le_bytes());
+        bytes.extend_from_slice(&self.persistence_information.to_le_bytes());
+        bytes.extend_from_slice(&self.reserved);
+        bytes
+    }
+}
+
+impl From<&CperHeader> for RecordSource {
+    fn from(header: &CperHeader) -> Self {
+        RecordSource {
+            severity: header.error_severity,
+            timestamp: (header.validation_bits & 2 != 0)
+                .then(|| Time::from_cper_timestamp(header.timestamp))
+                .flatten(),
+            platform_id: (header.validation_bits & 1 != 0).then_some(header.platform_id),
+            creator_id: Some(header.creator_id),
+            notification_type: Some(header.notification_type),
+            record_id: Some(header.record_id),
+            block_status: None,
+        }
+    }
 }
 
+/// Descriptor and content of a CPER section
+pub(crate) type SectionRef<'a> = (CperSectionDescriptor, &'a [u8]);
+
+/// Locates the sections of a CPER without copying their content.
+///
+/// Returns the record header followed by the descriptor and the content of each section.
+pub(crate) fn sections_from_slice(slice: &[u8]) -> Option<(CperHeader, Vec<SectionRef<'_>>)> {
+    let record_header = CperHeader::from_slice(slice.get(0..RECORD_HEADER_SIZE)?)?;
+
+    let sections = (0..record_header.section_count)
+        .map(|i| {
+            let index = RECORD_HEADER_SIZE + (i as usize * SECTION_DESCRIPTOR_SIZE);
+            let descriptor = CperSectionDescriptor::from_slice(slice.get(index..)?)?;
+            let offset = descriptor.section_offset as usize;
+            let end_offset = offset + descriptor.section_length as usize;
+            let data = slice.get(offset..end_offset)?;
+            Some((descriptor, data))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some((record_header, sections))
+}
+
+#[derive(Clone)]
 pub struct Cper {
     /// CPER Record Header
     pub record_header: CperHeader,
     /// CPER Sections
     pub sections: Vec<Section>,
+    /// Bytes of the record located after the last section
+    pub trailer: Vec<u8>,
 }
 
 impl Cper {
+    /// Builds a new CPER carrying the Crash Log records and the error sections of `crashlog`.
+    ///
+    /// The fields of the record header are restored from [crate::metadata::Metadata::source].
+    pub fn from_crashlog(crashlog: &CrashLog) -> Self {
+        let source = crashlog.metadata.source.as_ref();
+        let sections: Vec<Section> = crashlog
+            .export_sections()
+            .into_iter()
+            .enumerate()
+            .map(|(i, (section_type, source, section))| {
+                let mut descriptor = CperSectionDescriptor::new(section_type, source);
+                if i == 0 {
+                    // Primary section
+                    descriptor.flags |= 1;
+                }
+                Section {
+                    descriptor,
+                    section,
+                }
+            })
+            .collect();
+
+        let mut validation_bits = 0;
+        if source.and_then(|source| source.platform_id).is_some() {
+            validation_bits |= 1;
+        }
+        if source
+            .and_then(|source| source.timestamp.as_ref())
+            .is_some()
+        {
+            validation_bits |= 2;
+        }
+
+        let record_header = CperHeader {
+            signature_start: u32::from_le_bytes(*b"CPER"),
+            revision: Revision { minor: 1, major: 1 },
+            signature_end: 0xffffffff,
+            section_count: sections.len() as u16,
+            error_severity: source.map(|source| source.severity).unwrap_or_default(),
+            validation_bits,
+            record_length: 0,
+            timestamp: source
+                .and_then(|source| source.timestamp.as_ref())
+                .map(Time::to_cper_timestamp)
+                .unwrap_or_default(),
+            platform_id: source
+                .and_then(|source| source.platform_id)
+                .unwrap_or_default(),
+            partition_id: Guid::ZERO,
+            creator_id: source
+                .and_then(|source| source.creator_id)
+                .unwrap_or_default(),
+            notification_type: source
+                .and_then(|source| source.notification_type)
+                .unwrap_or(BOOT_NOTIFICATION_TYPE),
+            record_id: source
+                .and_then(|source| source.record_id)
+                .unwrap_or_default(),
+            flags: 0,
+            persistence_information: 0,
+            reserved: [0; 12],
+        };
+
+        Cper {
+            record_header,
+            sections,
+            trailer: Vec::new(),
+        }
+    }
+
+    /// Appends an error section to the record.
+    pub(crate) fn push_section(&mut self, section_type: Guid, section: CperSection) {
+        self.sections.push(Section {
+            descriptor: CperSectionDescriptor::new(section_type, None),
+            section,
+        });
+    }
+
     /// Decodes the CPER stored in a byte slice
     pub fn from_slice(slice: &[u8]) -> Option<Self> {
-        let record_header = CperHeader::from_slice(slice.get(0..RECORD_HEADER_SIZE)?)?;
-
-        let sections = (0..record_header.section_count)
-            .map(|i| {
-                let index = RECORD_HEADER_SIZE + (i as usize * SECTION_DESCRIPTOR_SIZE);
-                let descriptor = CperSectionDescriptor::from_slice(slice.get(index..)?)?;
-                let offset = descriptor.section_offset as usize;
-                let end_offset = offset + descriptor.section_length as usize;
-                let section = CperSection::from_slice(
-                    descriptor.section_type,
-                    slice.get(offset..end_offset)?,
-                )?;
+        let (record_header, sections) = sections_from_slice(slice)?;
+        let sections = sections
+            .into_iter()
+            .map(|(descriptor, data)| {
+                let section = CperSection::from_slice(descriptor.section_type, data)?;
                 Some(Section {
                     descriptor,
                     section,
@@ -160,9 +438,54 @@ impl Cper {
             })
             .collect::<Option<Vec<Section>>>()?;
 
+        let end = sections
+            .iter()
+            .map(|section| section.descriptor.section_offset + section.descriptor.section_length)
+            .chain([(RECORD_HEADER_SIZE + sections.len() * SECTION_DESCRIPTOR_SIZE) as u32])
+            .max()
+            .unwrap_or_default() as usize;
+        let record_end = slice.len().min(record_header.record_length as usize);
+
         Some(Cper {
             record_header,
             sections,
+            trailer: Vec::from(slice.get(end..record_end).unwrap_or_default()),
         })
     }
+
+    /// Exports the CPER as a sequence of bytes.
+    ///
+    /// The sections are written at the offsets given by their descriptors when possible. The
+    /// section count, the section lengths and the record length are updated to match the content
+    /// of the record.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut header = self.record_header.clone();
+        header.section_count = self.sections.len() as u16;
+
+        let data_offset = RECORD_HEADER_SIZE + self.sections.len() * SECTION_DESCRIPTOR_SIZE;
+        let mut cursor = data_offset;
+        let mut descriptors = Vec::new();
+        let mut payload = Vec::new();
+        for section in self.sections.iter() {
+            let data = section.section.to_bytes();
+            let offset = cursor.max(section.descriptor.section_offset as usize);
+            payload.resize(offset - data_offset, 0);
+            payload.extend_from_slice(&data);
+            cursor = offset + data.len();
+
+            let mut descriptor = section.descriptor.clone();
+            descriptor.section_offset = offset as u32;
+            descriptor.section_length = data.len() as u32;
+            descriptors.push(descriptor);
+        }
+        payload.extend_from_slice(&self.trailer);
+
+        header.record_length = (data_offset + payload.len()) as u32;
+        let mut bytes = header.to_bytes();
+        for descriptor in descriptors.iter() {
+            bytes.append(&mut descriptor.to_bytes());
+        }
+        bytes.append(&mut payload);
+        bytes
+    }
 }
@@ -1,6 +1,7 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
+use crate::region::Region;
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 #[cfg(not(feature = "std"))]
@@ -21,6 +22,7 @@ pub struct FirmwareErrorRecordHeader {
     pub guid: Guid,
 }
 
+#[derive(Clone)]
 pub struct FirmwareErrorRecord {
     pub header: FirmwareErrorRecordHeader,
     pub payload: Vec<u8>,
@@ -48,7 +50,9 @@ impl FirmwareErrorRecordHeader {
         bytes.push(self.revision);
         bytes.extend_from_slice(&self.reserved);
         bytes.extend_from_slice(&self.record_identifier.to_le_bytes());
-        bytes.extend_from_slice(&self.guid.to_bytes());
+        if self.revision >= 2 {
+            bytes.extend_from_slice(&self.guid.to_bytes());
+        }
         bytes
     }
 
@@ -61,7 +65,31 @@ impl FirmwareErrorRecordHeader {
     }
 }
 
+/// Returns the payload of a Firmware Error Record if it carries Crash Log records.
+pub(crate) fn crashlog_payload(s: &[u8]) -> Option<&[u8]> {
+    let header = FirmwareErrorRecordHeader::from_slice(s)?;
+    let guid = header.guid;
+    if guid != RECORD_ID_CRASHLOG {
+        log::info!("Ignoring unknown Firmware Error Record: {}", guid);
+        return None;
+    }
+    s.get(header.size()..)
+}
+
 impl FirmwareErrorRecord {
+    /// Builds a Firmware Error Record carrying the Crash Log records of `region`.
+    pub fn from_region(region: &Region) -> Self {
+        Self {
+            header: FirmwareErrorRecordHeader {
+                error_type: 2,
+                revision: 2,
+                guid: RECORD_ID_CRASHLOG,
+                ..FirmwareErrorRecordHeader::default()
+            },
+            payload: region.to_bytes(),
+        }
+    }
+
     pub fn from_slice(s: &[u8]) -> Option<FirmwareErrorRecord> {
         let header = FirmwareErrorRecordHeader::from_slice(s)?;
         let payload = Vec::from(s.get(header.size()..)?);
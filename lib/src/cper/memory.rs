@@ -0,0 +1,61 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+//! Platform Memory Error Section (UEFI 2.10 - Section N.2.5)
+
+use super::decode_fields;
+use crate::node::Node;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use uguid::Guid;
+
+pub const MEMORY_ERROR_SECTION_GUID: Guid = uguid::guid!("a5bc1114-6f64-4ede-b863-3e83ed7c83b1");
+
+const SECTION_SIZE: usize = 80;
+
+const FIELDS: &[(&str, usize, usize)] = &[
+    ("validation_bits", 0, 8),
+    ("error_status", 8, 8),
+    ("physical_address", 16, 8),
+    ("physical_address_mask", 24, 8),
+    ("node", 32, 2),
+    ("card", 34, 2),
+    ("module", 36, 2),
+    ("bank", 38, 2),
+    ("device", 40, 2),
+    ("row", 42, 2),
+    ("column", 44, 2),
+    ("bit_position", 46, 2),
+    ("requestor_id", 48, 8),
+    ("responder_id", 56, 8),
+    ("target_id", 64, 8),
+    ("memory_error_type", 72, 1),
+    ("extended", 73, 1),
+    ("rank_number", 74, 2),
+    ("card_handle", 76, 2),
+    ("module_handle", 78, 2),
+];
+
+/// Platform Memory Error Section
+#[derive(Clone)]
+pub struct MemoryErrorSection {
+    /// Raw content of the section
+    pub data: Vec<u8>,
+}
+
+impl MemoryErrorSection {
+    pub fn from_slice(s: &[u8]) -> Option<Self> {
+        if s.len() < SECTION_SIZE {
+            return None;
+        }
+        Some(MemoryErrorSection { data: Vec::from(s) })
+    }
+}
+
+impl From<&MemoryErrorSection> for Node {
+    fn from(section: &MemoryErrorSection) -> Self {
+        let mut node = Node::record("memory");
+        decode_fields(&mut node, &section.data, FIELDS);
+        node
+    }
+}
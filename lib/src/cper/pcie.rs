@@ -0,0 +1,72 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+//! PCI Express Error Section (UEFI 2.10 - Section N.2.7)
+
+use super::decode_fields;
+use crate::node::Node;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use uguid::Guid;
+
+pub const PCIE_ERROR_SECTION_GUID: Guid = uguid::guid!("d995e954-bbc1-430f-ad91-b44dcb3c6f35");
+
+const SECTION_SIZE: usize = 208;
+
+const FIELDS: &[(&str, usize, usize)] = &[
+    ("validation_bits", 0, 8),
+    ("port_type", 8, 4),
+    ("version", 12, 4),
+    ("command", 16, 2),
+    ("status", 18, 2),
+    ("device_id.vendor_id", 24, 2),
+    ("device_id.device_id", 26, 2),
+    ("device_id.class_code", 28, 3),
+    ("device_id.function", 31, 1),
+    ("device_id.device", 32, 1),
+    ("device_id.segment", 33, 2),
+    ("device_id.primary_bus", 35, 1),
+    ("device_id.secondary_bus", 36, 1),
+    ("device_id.slot", 37, 2),
+    ("serial_number", 40, 8),
+    ("bridge.secondary_status", 48, 2),
+    ("bridge.control", 50, 2),
+    ("aer.capability_header", 112, 4),
+    ("aer.uncorrectable_error_status", 116, 4),
+    ("aer.uncorrectable_error_mask", 120, 4),
+    ("aer.uncorrectable_error_severity", 124, 4),
+    ("aer.correctable_error_status", 128, 4),
+    ("aer.correctable_error_mask", 132, 4),
+    ("aer.capabilities_and_control", 136, 4),
+    ("aer.header_log.dw0", 140, 4),
+    ("aer.header_log.dw1", 144, 4),
+    ("aer.header_log.dw2", 148, 4),
+    ("aer.header_log.dw3", 152, 4),
+    ("aer.root_error_command", 156, 4),
+    ("aer.root_error_status", 160, 4),
+    ("aer.error_source_id", 164, 4),
+];
+
+/// PCI Express Error Section
+#[derive(Clone)]
+pub struct PcieErrorSection {
+    /// Raw content of the section
+    pub data: Vec<u8>,
+}
+
+impl PcieErrorSection {
+    pub fn from_slice(s: &[u8]) -> Option<Self> {
+        if s.len() < SECTION_SIZE {
+            return None;
+        }
+        Some(PcieErrorSection { data: Vec::from(s) })
+    }
+}
+
+impl From<&PcieErrorSection> for Node {
+    fn from(section: &PcieErrorSection) -> Self {
+        let mut node = Node::record("pcie");
+        decode_fields(&mut node, &section.data, FIELDS);
+        node
+    }
+}
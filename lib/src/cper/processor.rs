@@ -0,0 +1,155 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+//! IA32/X64 Processor Error Section (UEFI 2.10 - Section N.2.4.2)
+
+use super::decode_fields;
+use crate::node::Node;
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec::Vec};
+use uguid::Guid;
+
+pub const PROCESSOR_ERROR_SECTION_GUID: Guid = uguid::guid!("dc3ea0b0-a144-4797-b95b-53fa242b6e1d");
+
+const CACHE_CHECK_GUID: Guid = uguid::guid!("a55701f5-e3ef-43de-ac72-249b573fad2c");
+const TLB_CHECK_GUID: Guid = uguid::guid!("fc06b535-5e1f-4562-9f25-0a3b9adb63c3");
+const BUS_CHECK_GUID: Guid = uguid::guid!("1cf3f8b3-c5b1-49a2-aa59-5eef92ffa63c");
+const MS_CHECK_GUID: Guid = uguid::guid!("48ab7f57-dc34-4f6c-a7d3-b0b5b0a74314");
+
+const HEADER_SIZE: usize = 64;
+const ERROR_INFO_SIZE: usize = 64;
+const CONTEXT_INFO_HEADER_SIZE: usize = 16;
+
+const HEADER_FIELDS: &[(&str, usize, usize)] = &[
+    ("validation_bits", 0, 8),
+    ("local_apic_id", 8, 8),
+    ("cpuid.eax", 16, 8),
+    ("cpuid.ebx", 24, 8),
+    ("cpuid.ecx", 32, 8),
+    ("cpuid.edx", 40, 8),
+];
+
+const ERROR_INFO_FIELDS: &[(&str, usize, usize)] = &[
+    ("validation_bits", 16, 8),
+    ("check_info", 24, 8),
+    ("target_id", 32, 8),
+    ("requestor_id", 40, 8),
+    ("responder_id", 48, 8),
+    ("instruction_ip", 56, 8),
+];
+
+const CONTEXT_INFO_FIELDS: &[(&str, usize, usize)] = &[
+    ("register_context_type", 0, 2),
+    ("register_array_size", 2, 2),
+    ("msr_address", 4, 4),
+    ("mm_register_address", 8, 8),
+];
+
+/// IA32/X64 Processor Error Section
+#[derive(Clone)]
+pub struct ProcessorErrorSection {
+    /// Raw content of the section
+    pub data: Vec<u8>,
+}
+
+impl ProcessorErrorSection {
+    pub fn from_slice(s: &[u8]) -> Option<Self> {
+        if s.len() < HEADER_SIZE {
+            return None;
+        }
+        let section = ProcessorErrorSection { data: Vec::from(s) };
+        let error_info_end = HEADER_SIZE + section.error_info_count() * ERROR_INFO_SIZE;
+        if s.len() < error_info_end {
+            return None;
+        }
+        Some(section)
+    }
+
+    fn validation_bits(&self) -> u64 {
+        self.data
+            .get(0..8)
+            .and_then(|bits| bits.try_into().ok())
+            .map(u64::from_le_bytes)
+            .unwrap_or_default()
+    }
+
+    /// Number of Processor Error Information Structures
+    pub fn error_info_count(&self) -> usize {
+        ((self.validation_bits() >> 2) & 0x3f) as usize
+    }
+
+    /// Number of Processor Context Information Structures
+    pub fn context_info_count(&self) -> usize {
+        ((self.validation_bits() >> 8) & 0x3f) as usize
+    }
+
+    /// Returns the content of the Processor Error Information Structures.
+    fn error_infos(&self) -> impl Iterator<Item = &[u8]> {
+        self.data
+            .get(HEADER_SIZE..)
+            .unwrap_or_default()
+            .as_chunks::<ERROR_INFO_SIZE>()
+            .0
+            .iter()
+            .take(self.error_info_count())
+            .map(|error_info| error_info.as_slice())
+    }
+
+    /// Returns the content of the Processor Context Information Structures.
+    fn context_infos(&self) -> Vec<&[u8]> {
+        let mut context_infos = Vec::new();
+        let mut cursor = HEADER_SIZE + self.error_info_count() * ERROR_INFO_SIZE;
+
+        for _ in 0..self.context_info_count() {
+            let Some(header) = self.data.get(cursor..cursor + CONTEXT_INFO_HEADER_SIZE) else {
+                log::warn!("Truncated Processor Context Information Structure");
+                break;
+            };
+            let array_size = u16::from_le_bytes([header[2], header[3]]) as usize;
+            context_infos.push(header);
+            cursor += CONTEXT_INFO_HEADER_SIZE + array_size;
+        }
+
+        context_infos
+    }
+}
+
+/// Returns the index of the type of a Processor Error Information Structure: 0 (cache), 1 (TLB),
+/// 2 (bus), 3 (micro-architecture specific), or 0xff (unknown).
+fn error_structure_type(error_info: &[u8]) -> u64 {
+    let guid = error_info
+        .get(0..16)
+        .and_then(|guid| guid.try_into().ok())
+        .map(Guid::from_bytes)
+        .unwrap_or(Guid::ZERO);
+
+    match guid {
+        CACHE_CHECK_GUID => 0,
+        TLB_CHECK_GUID => 1,
+        BUS_CHECK_GUID => 2,
+        MS_CHECK_GUID => 3,
+        _ => 0xff,
+    }
+}
+
+impl From<&ProcessorErrorSection> for Node {
+    fn from(section: &ProcessorErrorSection) -> Self {
+        let mut node = Node::record("processor");
+        decode_fields(&mut node, &section.data, HEADER_FIELDS);
+
+        for (i, error_info) in section.error_infos().enumerate() {
+            let mut error_info_node = Node::section(&format!("error_info{i}"));
+            error_info_node.add(Node::field("type", error_structure_type(error_info)));
+            decode_fields(&mut error_info_node, error_info, ERROR_INFO_FIELDS);
+            node.add(error_info_node);
+        }
+
+        for (i, context_info) in section.context_infos().into_iter().enumerate() {
+            let mut context_info_node = Node::section(&format!("context_info{i}"));
+            decode_fields(&mut context_info_node, context_info, CONTEXT_INFO_FIELDS);
+            node.add(context_info_node);
+        }
+
+        node
+    }
+}
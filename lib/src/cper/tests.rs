@@ -3,6 +3,8 @@
 
 use super::Cper;
 use crate::CrashLog;
+use crate::container::Container;
+use crate::metadata::Time;
 
 pub const FW_ERROR_RECORD_GUID: uguid::Guid = uguid::guid!("81212a96-09ed-4996-9471-8d729c8e69ed");
 
@@ -25,7 +27,7 @@ fn from_slice() {
 #[test]
 fn cl_from_cper() {
     let cper = Cper::from_slice(&std::fs::read("tests/samples/cper.whea").unwrap()).unwrap();
-    let crashlog = CrashLog::from_cper(cper);
+    let crashlog = CrashLog::from_container(Container::Cper(cper));
     assert!(crashlog.is_ok());
     let crashlog = crashlog.unwrap();
 
@@ -41,3 +43,109 @@ fn cl_from_cper() {
 
     assert_eq!(records.len(), 3);
 }
+
+#[test]
+fn error_sections() {
+    let bert = std::fs::read("tests/samples/cper_sections.bert").unwrap();
+    let crashlog = CrashLog::from_slice(&bert).unwrap();
+    assert_eq!(crashlog.regions.len(), 2);
+    assert_eq!(crashlog.cper_sections.len(), 3);
+
+    let root = crashlog.decode_without_cm();
+    let field = |path: &str| match root.get_by_path(path).unwrap().kind {
+        crate::node::NodeType::Field { value } => value,
+        _ => panic!("{path} is not a field"),
+    };
+
+    assert_eq!(field("cper.processor.local_apic_id"), 0x24);
+    assert_eq!(field("cper.processor.cpuid.eax"), 0x806f8);
+    assert_eq!(field("cper.processor.error_info0.type"), 0);
+    assert_eq!(field("cper.processor.error_info0.check_info"), 0x120061);
+    assert_eq!(field("cper.processor.context_info0.msr_address"), 0x400);
+    assert_eq!(field("cper.memory.physical_address"), 0x1234567000);
+    assert_eq!(field("cper.memory.row"), 0x1a2b);
+    assert_eq!(field("cper.memory.memory_error_type"), 2);
+    assert_eq!(field("cper.pcie.device_id.vendor_id"), 0x8086);
+    assert_eq!(field("cper.pcie.device_id.primary_bus"), 0x16);
+    assert_eq!(field("cper.pcie.aer.uncorrectable_error_status"), 0x4000);
+    assert!(root.get_by_path("mca").is_some());
+
+    let crashlog = CrashLog::from_slice(&crashlog.to_bytes()).unwrap();
+    assert_eq!(crashlog.cper_sections.len(), 3);
+}
+
+#[test]
+fn source() {
+    let data = std::fs::read("tests/samples/cper.whea").unwrap();
+    let cper = Cper::from_slice(&data).unwrap();
+    let record_id = cper.record_header.record_id;
+    let severity = cper.sections[0].descriptor.section_severity;
+
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    let source = crashlog.metadata.source.as_ref().unwrap();
+    assert_eq!(source.record_id, Some(record_id));
+    let time = Time {
+        year: 2022,
+        month: 1,
+        day: 7,
+        hour: 16,
+        minute: 46,
+    };
+    assert_eq!(source.timestamp, Some(time.clone()));
+    assert_eq!(crashlog.metadata.time, Some(time));
+    assert_eq!(
+        crashlog.regions[0].source.as_ref().unwrap().severity,
+        severity
+    );
+
+    let root = crashlog.decode_without_cm();
+    assert_eq!(
+        root.get_by_path("source.record.record_id").unwrap().kind,
+        crate::node::NodeType::Field { value: record_id }
+    );
+    assert!(root.get_by_path("source.region0.severity").is_some());
+    assert!(root.get_by_path("source.region2.severity").is_some());
+}
+
+#[test]
+fn timestamp() {
+    // 2025-12-31 23:59:58, precise, encoded in BCD
+    let bcd = Time::from_cper_timestamp(0x20_25_12_31_01_23_59_58).unwrap();
+    assert_eq!(bcd.to_string(), "2025-12-31-23-59");
+    assert_eq!(bcd.to_cper_timestamp(), 0x20_25_12_31_00_23_59_00);
+    assert_eq!(
+        Time::from_cper_timestamp(bcd.to_cper_timestamp()),
+        Some(bcd)
+    );
+
+    // Same time, encoded in binary
+    let binary = Time::from_cper_timestamp(0x14_19_0c_1f_00_17_3b_3a).unwrap();
+    assert_eq!(binary.to_string(), "2025-12-31-23-59");
+
+    assert_eq!(Time::from_cper_timestamp(0), None);
+    assert_eq!(Time::from_cper_timestamp(0x20_25_13_31_00_23_59_58), None);
+}
+
+#[test]
+fn truncated_processor_section() {
+    assert!(super::processor::ProcessorErrorSection::from_slice(&[0; 4]).is_none());
+
+    let bert = std::fs::read("tests/samples/cper_sections.bert").unwrap();
+    let mut crashlog = CrashLog::from_slice(&bert).unwrap();
+    crashlog.container = None;
+    let mut data = crashlog.export(crate::container::ContainerType::Cper);
+
+    let section_count = Cper::from_slice(&data).unwrap().sections.len();
+    let descriptor = (0..section_count)
+        .map(|i| super::RECORD_HEADER_SIZE + i * super::SECTION_DESCRIPTOR_SIZE)
+        .find(|offset| {
+            data[offset + 16..offset + 32]
+                == super::processor::PROCESSOR_ERROR_SECTION_GUID.to_bytes()
+        })
+        .unwrap();
+    data[descriptor + 4..descriptor + 8].copy_from_slice(&4u32.to_le_bytes());
+
+    let crashlogs = CrashLog::all_from_slice(&data).unwrap();
+    assert_eq!(crashlogs[0].regions.len(), 2);
+    assert_eq!(crashlogs[0].cper_sections.len(), 2);
+}
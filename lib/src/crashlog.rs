@@ -1,27 +1,79 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
+mod borrowed;
+#[cfg(all(feature = "collateral_manager", feature = "serialize", feature = "std"))]
+mod stream;
+
 use crate::Error;
-use crate::bert::{Berr, Bert};
 #[cfg(feature = "collateral_manager")]
 use crate::collateral::{CollateralManager, CollateralTree};
-use crate::cper::Cper;
+use crate::container::{self, Container, ContainerType};
+use crate::cper::fer::FirmwareErrorRecord;
+use crate::cper::{CperSection, FW_ERROR_RECORD_GUID};
 #[cfg(feature = "extraction")]
 use crate::extract;
-use crate::metadata::Metadata;
+use crate::import;
+use crate::metadata::{Metadata, SectionSource};
 use crate::node::Node;
+#[cfg(feature = "collateral_manager")]
+use crate::node::PathFilter;
 use crate::region::Region;
+use crate::smbios::SMBIOS_SECTION_GUID;
 #[cfg(not(feature = "std"))]
-use alloc::{collections::VecDeque, vec, vec::Vec};
+use alloc::{collections::VecDeque, format, vec, vec::Vec};
+pub use borrowed::CrashLogRef;
 #[cfg(target_os = "uefi")]
 use core::ptr::NonNull;
 #[cfg(feature = "std")]
 use std::collections::VecDeque;
 #[cfg(target_os = "uefi")]
 use uefi_raw::table::system::SystemTable;
+use uguid::Guid;
+
+/// Appends the regions stored in the Box records after the region that contains them.
+fn expand_box_regions<R>(regions: Vec<R>, box_regions: impl Fn(&R, usize) -> Vec<R>) -> Vec<R> {
+    let mut queue = VecDeque::from(regions);
+    let mut regions = Vec::new();
+
+    while let Some(region) = queue.pop_front() {
+        for box_region in box_regions(&region, regions.len()) {
+            queue.push_front(box_region)
+        }
+        regions.push(region)
+    }
+
+    regions
+}
+
+/// Returns the register tree representation of the metadata: the error record and sections that
+/// contained the Crash Log, located under the `source` branch, and the platform identity, located
+/// under the `platform` branch.
+fn decode_metadata<'a>(
+    metadata: &Metadata,
+    regions: impl Iterator<Item = Option<&'a SectionSource>>,
+) -> Node {
+    let mut source = Node::section("source");
+    if let Some(record) = metadata.source.as_ref() {
+        source.add(Node::from(record));
+    }
+    for (i, section) in regions.enumerate() {
+        if let Some(section) = section {
+            let mut node = Node::from(section);
+            node.name = format!("region{i}");
+            source.add(node);
+        }
+    }
 
-use crate::header::HeaderType;
-use crate::header::record_types;
+    let mut root = Node::root();
+    if source.children().next().is_some() {
+        root.add(source);
+    }
+    if let Some(platform) = metadata.platform.as_ref() {
+        root.add(Node::from(platform));
+    }
+    root
+}
 
 /// Set of all the Crash Log records captured on a platform.
 #[derive(Default)]
@@ -30,40 +82,18 @@ pub struct CrashLog {
     pub regions: Vec<Region>,
     /// Extra information extracted alongside the Crash Log records
     pub metadata: Metadata,
+    /// Standard error sections (processor, memory, PCIe) reported alongside the Crash Log records
+    pub cper_sections: Vec<CperSection>,
+    /// Original binary container of the Crash Log records.
+    ///
+    /// When present, [CrashLog::to_bytes] writes it back unmodified. It must be cleared for the
+    /// changes made to the regions and the metadata to be exported.
+    pub container: Option<Container>,
 }
 
 impl CrashLog {
-    fn from_regions(regions: Vec<Region>) -> Result<Self, Error> {
-        let mut queue = VecDeque::from(regions);
-        let mut regions = Vec::new();
-
-        while let Some(region) = queue.pop_front() {
-            for record in region.records.iter() {
-                if record.header.version.record_type != record_types::BOX {
-                    continue;
-                }
-
-                let Some(payload) = record.data.get(record.header.header_size()..) else {
-                    log::error!("The Box record has an empty payload");
-                    continue;
-                };
-
-                match Region::from_slice(payload) {
-                    Ok(mut region) => {
-                        if let HeaderType::Type6 {
-                            socket_id, die_id, ..
-                        } = record.header.header_type
-                        {
-                            region.set_socket_and_die_ids(socket_id, die_id)
-                        };
-                        queue.push_front(region)
-                    }
-                    Err(err) => log::error!("Invalid region in Box record: {err}"),
-                }
-            }
-
-            regions.push(region)
-        }
+    pub(crate) fn from_regions(regions: Vec<Region>) -> Result<Self, Error> {
+        let regions = expand_box_regions(regions, Region::box_regions);
 
         if regions.is_empty() {
             return Err(Error::InvalidCrashLog);
@@ -75,14 +105,28 @@ impl CrashLog {
         })
     }
 
-    /// Extracts the Crash Log records from [Berr].
-    pub(crate) fn from_berr(berr: Berr) -> Result<Self, Error> {
-        let regions = berr
-            .entries
+    /// Extracts the Crash Log records from a [Container] which is retained in the [CrashLog].
+    pub(crate) fn from_container(container: Container) -> Result<Self, Error> {
+        let mut crashlog = CrashLog::from_container_slice(&container.to_bytes())?;
+        crashlog.container = Some(container);
+        Ok(crashlog)
+    }
+
+    /// Extracts the Crash Log records and the standard error sections from the container stored
+    /// in a byte slice.
+    ///
+    /// The records are decoded by [CrashLogRef] and copied once. The container itself is not
+    /// retained.
+    fn from_container_slice(s: &[u8]) -> Result<Self, Error> {
+        let (record, sections) = container::sections_from_slice(s).ok_or(Error::InvalidCrashLog)?;
+        let mut crashlog = CrashLog::from(CrashLogRef::from_sections(record, &sections)?);
+        crashlog.cper_sections = sections
             .iter()
-            .filter_map(|entry| Region::from_cper_section(&entry.cper_section))
+            .filter(|section| section.section_type != FW_ERROR_RECORD_GUID)
+            .filter_map(|section| CperSection::from_slice(section.section_type, section.data))
+            .filter(CperSection::is_error_section)
             .collect();
-        CrashLog::from_regions(regions)
+        Ok(crashlog)
     }
 
     #[cfg(all(target_os = "uefi", feature = "extraction"))]
@@ -103,53 +147,252 @@ impl CrashLog {
     #[cfg(any(all(target_os = "linux", feature = "extraction"), doc))]
     /// Reads the Crash Log reported through ACPI from the linux sysfs
     pub fn from_linux_sysfs() -> Result<Self, Error> {
-        extract::sysfs::read_berr_from_sysfs().and_then(CrashLog::from_berr)
+        let mut crashlog =
+            extract::sysfs::read_bert_from_sysfs().and_then(CrashLog::from_container)?;
+        crashlog.metadata.platform = extract::sysfs::read_platform_from_sysfs()
+            .inspect_err(|err| log::warn!("Cannot read the SMBIOS tables: {err}"))
+            .ok();
+        Ok(crashlog)
     }
 
-    /// Extracts the Crash Log records from [Cper] record.
-    pub(crate) fn from_cper(cper: Cper) -> Result<Self, Error> {
-        let regions: Vec<Region> = cper
-            .sections
-            .iter()
-            .filter_map(|section| Region::from_cper_section(&section.section))
-            .collect();
+    #[cfg(any(all(target_os = "linux", feature = "extraction"), doc))]
+    /// Reads the Crash Logs persisted in the ACPI ERST through the linux pstore file system
+    pub fn from_linux_pstore() -> Result<Vec<Self>, Error> {
+        let path = std::path::Path::new(extract::pstore::PSTORE_PATH);
+        let mut crashlogs = extract::pstore::read_crashlogs_from_pstore(path, None)?;
+        if crashlogs.is_empty() {
+            return Err(Error::NoCrashLogFound);
+        }
+        let platform = extract::sysfs::read_platform_from_sysfs()
+            .inspect_err(|err| log::warn!("Cannot read the SMBIOS tables: {err}"))
+            .ok();
+        for crashlog in crashlogs.iter_mut() {
+            crashlog.metadata.platform = platform.clone();
+        }
+        Ok(crashlogs)
+    }
 
-        if regions.is_empty() {
+    #[cfg(any(all(target_os = "linux", feature = "extraction"), doc))]
+    /// Reads all the Crash Logs reported by the linux kernel.
+    ///
+    /// The Crash Log reported through the BERT (see [CrashLog::from_linux_sysfs]) is followed by
+    /// the ones persisted in the ERST (see [CrashLog::from_linux_pstore]). The ERST entries that
+    /// duplicate the BERT capture are skipped.
+    pub fn from_linux() -> Result<Vec<Self>, Error> {
+        let mut crashlogs = Vec::new();
+        match CrashLog::from_linux_sysfs() {
+            Ok(crashlog) => crashlogs.push(crashlog),
+            Err(Error::NoCrashLogFound) => (),
+            Err(err) => log::warn!("Cannot read the Crash Log from the BERT: {err}"),
+        }
+
+        let path = std::path::Path::new(extract::pstore::PSTORE_PATH);
+        match extract::pstore::read_crashlogs_from_pstore(path, crashlogs.first()) {
+            Ok(mut erst) => {
+                let platform = crashlogs
+                    .first()
+                    .and_then(|crashlog| crashlog.metadata.platform.clone())
+                    .or_else(|| extract::sysfs::read_platform_from_sysfs().ok());
+                for crashlog in erst.iter_mut() {
+                    crashlog.metadata.platform = platform.clone();
+                }
+                crashlogs.append(&mut erst)
+            }
+            Err(err) => log::warn!("Cannot read the Crash Logs from the ERST: {err}"),
+        }
+
+        if crashlogs.is_empty() {
             return Err(Error::NoCrashLogFound);
         }
+        Ok(crashlogs)
+    }
+
+    /// Reconstructs the Crash Logs printed by the BERT and GHES drivers of the Linux kernel.
+    ///
+    /// The `log` argument is expected to contain the output of `dmesg` or `journalctl`. Each
+    /// error event that carries Crash Log records is returned as a separate [CrashLog].
+    pub fn from_kernel_log(log: &str) -> Result<Vec<Self>, Error> {
+        import::kernel_log::crashlogs_from_kernel_log(log)
+    }
+
+    /// Reconstructs the Crash Log from the ACPI tables dumped by `acpidump` or `acpixtract`.
+    ///
+    /// `tables` contains either the text output of `acpidump` or the binary BERT extracted by
+    /// `acpixtract`. As these tools do not capture the Boot Error Region referenced by the BERT,
+    /// its content must be provided separately through `region`. If it is missing,
+    /// [Error::MissingBootErrorRecordRegion] reports its address and length.
+    pub fn from_acpi_dump(tables: &[u8], region: Option<&[u8]>) -> Result<Self, Error> {
+        import::acpidump::crashlog_from_acpi_dump(tables, region)
+    }
+
+    #[cfg(feature = "serialize")]
+    /// Reconstructs the Crash Log stored in a crashdump file collected by OpenBMC.
+    ///
+    /// The records found in the `crashlog` section of each processor are tagged with the socket
+    /// and die IDs derived from the structure of the crashdump.
+    pub fn from_openbmc_crashdump(s: &[u8]) -> Result<Self, Error> {
+        import::crashdump::crashlog_from_crashdump(s)
+    }
 
-        CrashLog::from_regions(regions)
+    #[cfg(feature = "rasdaemon")]
+    /// Reads the Crash Logs recorded by rasdaemon in its SQLite database.
+    ///
+    /// The database of the local host (`/var/lib/rasdaemon/ras-mc_event.db`) is used if `path` is
+    /// not provided. In that case, the host name and the platform identity are also captured.
+    pub fn from_rasdaemon(path: Option<&std::path::Path>) -> Result<Vec<Self>, Error> {
+        import::rasdaemon::crashlogs_from_rasdaemon_db(path)
     }
 
     /// Decodes a raw Crash Log binary.
+    ///
+    /// The binary can either be a BERT, a CPER, or a single Crash Log region. The container is
+    /// retained, such that it can be written back unmodified.
     pub fn from_slice(s: &[u8]) -> Result<Self, Error> {
-        if let Some(berr) = Berr::from_bert_file(s) {
-            CrashLog::from_berr(berr)
-        } else if let Some(cper) = Cper::from_slice(s) {
-            CrashLog::from_cper(cper)
-        } else {
+        let Some(container) = Container::from_slice(s) else {
             // Input file is a single Crash Log region
-            CrashLog::from_regions(vec![Region::from_slice(s)?])
+            return CrashLogRef::from_slice(s).map(CrashLog::from);
+        };
+
+        let mut crashlog = CrashLog::from_container_slice(s)?;
+        crashlog.container = Some(container);
+        Ok(crashlog)
+    }
+
+    /// Returns the sections to be written in a new container: a Firmware Error Record for each
+    /// top-level region, followed by the standard error sections.
+    pub(crate) fn export_sections(&self) -> Vec<(Guid, Option<&SectionSource>, CperSection)> {
+        let mut sections: Vec<(Guid, Option<&SectionSource>, CperSection)> = self
+            .regions
+            .iter()
+            .filter(|region| region.parent.is_none())
+            .map(|region| {
+                (
+                    FW_ERROR_RECORD_GUID,
+                    region.source.as_ref(),
+                    CperSection::FirmwareErrorRecord(FirmwareErrorRecord::from_region(region)),
+                )
+            })
+            .collect();
+
+        for section in self.cper_sections.iter() {
+            if let Some(section_type) = section.section_type() {
+                sections.push((section_type, None, section.clone()));
+            }
+        }
+
+        sections
+    }
+
+    /// Decodes a binary made of several consecutive BERT or CPER records.
+    ///
+    /// A [CrashLog] is returned for each record that contains Crash Log records. The records
+    /// that do not carry any Crash Log are skipped. If the binary does not start with a BERT or
+    /// a CPER, it is decoded as a single Crash Log region.
+    pub fn all_from_slice(s: &[u8]) -> Result<Vec<Self>, Error> {
+        let containers = container::split(s);
+        if containers.is_empty() {
+            return Ok(vec![CrashLog::from_slice(s)?]);
         }
+
+        let mut crashlogs = Vec::new();
+        let mut error = Error::NoCrashLogFound;
+        for (i, slice) in containers.into_iter().enumerate() {
+            match CrashLog::from_slice(slice) {
+                Ok(crashlog) => crashlogs.push(crashlog),
+                Err(err) => {
+                    log::info!("Skipping record {i}: {err}");
+                    error = err;
+                }
+            }
+        }
+
+        if crashlogs.is_empty() {
+            return Err(error);
+        }
+        Ok(crashlogs)
     }
 
     /// Exports the [CrashLog] as a sequence of bytes.
+    ///
+    /// The original [Container] is written if it has been retained. Otherwise, a new BERT is
+    /// built from the regions and the metadata of the [CrashLog].
     pub fn to_bytes(&self) -> Vec<u8> {
-        let mut berr = Berr::from_crashlog(self).to_bytes();
-        let bert = Bert {
-            region: 0,
-            region_length: berr.len() as u32,
-            ..Bert::dummy()
-        };
+        match self.container.as_ref() {
+            Some(container) => container.to_bytes(),
+            None => self.export(ContainerType::Bert),
+        }
+    }
 
-        let mut bytes = bert.to_bytes();
-        bytes.append(&mut berr);
-        bytes
+    /// Exports the [CrashLog] in a container of the given type.
+    ///
+    /// The original [Container] is written if it is of the requested type. Otherwise, a new
+    /// container is built from the regions and the metadata of the [CrashLog].
+    pub fn export(&self, container_type: ContainerType) -> Vec<u8> {
+        Container::export(self, container_type).to_bytes()
+    }
+
+    /// Exports the [CrashLog] in a new container of the given type that also carries the
+    /// platform identity.
+    ///
+    /// The [Metadata::platform] is written as an SMBIOS structure table in a section of type
+    /// [crate::smbios::SMBIOS_SECTION_GUID]. This section type is specific to this library and
+    /// is ignored by the other tools. The platform identity is restored when the exported binary
+    /// is decoded.
+    pub fn export_with_platform(&self, container_type: ContainerType) -> Vec<u8> {
+        let mut container = Container::from_crashlog(self, container_type);
+        if let Some(platform) = self.metadata.platform.as_ref() {
+            container.push_section(
+                SMBIOS_SECTION_GUID,
+                CperSection::Unknown(platform.to_smbios_table()),
+            );
+        }
+        container.to_bytes()
+    }
+
+    /// Exports the top-level regions of the [CrashLog] without any container.
+    ///
+    /// A separate binary is returned for each region, as the regions cannot be delimited once
+    /// concatenated.
+    pub fn export_regions(&self) -> Vec<Vec<u8>> {
+        self.regions
+            .iter()
+            .filter(|region| region.parent.is_none())
+            .map(Region::to_bytes)
+            .collect()
+    }
+
+    /// Returns the register tree representation of the standard error sections, located under
+    /// the `cper` branch.
+    fn decode_cper_sections(&self) -> Node {
+        let mut root = Node::root();
+        if self.cper_sections.is_empty() {
+            return root;
+        }
+
+        let mut cper = Node::section("cper");
+        for section in self.cper_sections.iter() {
+            if let Some(node) = section.decode() {
+                let mut section_root = Node::root();
+                section_root.add(node);
+                cper.merge(section_root);
+            }
+        }
+        root.add(cper);
+        root
+    }
+
+    /// Returns the register tree representation of the metadata of the Crash Log.
+    fn decode_metadata(&self) -> Node {
+        decode_metadata(
+            &self.metadata,
+            self.regions.iter().map(|region| region.source.as_ref()),
+        )
     }
 
     /// Returns the register tree representation of the Crash Log record headers.
     pub fn decode_without_cm(&self) -> Node {
-        let mut root = Node::root();
+        let mut root = self.decode_cper_sections();
+        root.merge(self.decode_metadata());
         for region in self.regions.iter() {
             for record in region.records.iter() {
                 root.merge(record.decode_without_cm())
@@ -160,8 +403,9 @@ impl CrashLog {
 
     /// Returns the register tree representation of the Crash Log record content.
     #[cfg(feature = "collateral_manager")]
-    pub fn decode<T: CollateralTree>(&self, cm: &mut CollateralManager<T>) -> Node {
-        let mut root = Node::root();
+    pub fn decode<T: CollateralTree>(&self, cm: &CollateralManager<T>) -> Node {
+        let mut root = self.decode_cper_sections();
+        root.merge(self.decode_metadata());
         for region in self.regions.iter() {
             for record in region.records.iter() {
                 root.merge(record.decode(cm))
@@ -169,4 +413,44 @@ impl CrashLog {
         }
         root
     }
+
+    /// Returns the register tree representation of the Crash Log record content, limited to the
+    /// given `paths`.
+    ///
+    /// Each path selects the node it points to and all its descendants. The node names can
+    /// contain `*` wildcards (see [PathFilter]). Only the fields of the decode definitions that
+    /// match the paths are decoded, which is significantly faster than decoding the whole
+    /// register tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let data = std::fs::read("tests/samples/three_strike_timeout.crashlog").unwrap();
+    /// let crashlog = CrashLog::from_slice(&data).unwrap();
+    /// let cm = CollateralManager::embedded_tree().unwrap();
+    ///
+    /// let nodes = crashlog.decode_paths(&cm, &["core*.thread.arch_state.lip"]);
+    /// let lip = nodes.get_by_path("core0.thread.arch_state.lip").unwrap();
+    /// assert_eq!(lip.kind, NodeType::Field { value: 0xfffff80577036530 });
+    /// assert!(nodes.get_by_path("core0.thread.arch_state.mca").is_none());
+    /// ```
+    #[cfg(feature = "collateral_manager")]
+    pub fn decode_paths<T: CollateralTree, S: AsRef<str>>(
+        &self,
+        cm: &CollateralManager<T>,
+        paths: &[S],
+    ) -> Node {
+        let filter = PathFilter::new(paths);
+        let mut root = self.decode_cper_sections();
+        root.merge(self.decode_metadata());
+        for region in self.regions.iter() {
+            for record in region.records.iter() {
+                root.merge(record.decode_with_filter(cm, &filter))
+            }
+        }
+        root.retain(&filter);
+        root
+    }
 }
@@ -0,0 +1,145 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::{CrashLog, decode_metadata, expand_box_regions};
+use crate::Error;
+#[cfg(feature = "collateral_manager")]
+use crate::collateral::{CollateralManager, CollateralTree};
+use crate::container::{self, SectionRef};
+use crate::cper::FW_ERROR_RECORD_GUID;
+use crate::metadata::{Metadata, Platform, RecordSource};
+use crate::node::Node;
+#[cfg(feature = "collateral_manager")]
+use crate::node::PathFilter;
+use crate::region::{Region, RegionRef};
+use crate::smbios::SMBIOS_SECTION_GUID;
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+/// A [CrashLog] whose records point to the binary it has been decoded from.
+///
+/// Neither the records nor the content of the BERT or CPER that contains them are copied, which
+/// makes it suitable for processing large amounts of captures. Only the Crash Log records, the
+/// headers of the error record and the platform identity are decoded. The other error sections
+/// are ignored.
+#[derive(Default)]
+pub struct CrashLogRef<'a> {
+    /// Crash Log regions captured on the platform.
+    pub regions: Vec<RegionRef<'a>>,
+    /// Extra information extracted alongside the Crash Log records
+    pub metadata: Metadata,
+}
+
+impl<'a> CrashLogRef<'a> {
+    fn from_regions(regions: Vec<RegionRef<'a>>) -> Result<Self, Error> {
+        let regions = expand_box_regions(regions, RegionRef::box_regions);
+        if regions.is_empty() {
+            return Err(Error::InvalidCrashLog);
+        }
+
+        Ok(CrashLogRef {
+            regions,
+            ..CrashLogRef::default()
+        })
+    }
+
+    /// Decodes a raw Crash Log binary without copying its content.
+    ///
+    /// The binary can either be a BERT, a CPER, or a single Crash Log region.
+    pub fn from_slice(s: &'a [u8]) -> Result<Self, Error> {
+        match container::sections_from_slice(s) {
+            Some((record, sections)) => CrashLogRef::from_sections(record, &sections),
+            // Input file is a single Crash Log region
+            None => CrashLogRef::from_regions(vec![RegionRef::from_slice(s)?]),
+        }
+    }
+
+    /// Decodes the Crash Log records stored in the error sections of a container.
+    pub(crate) fn from_sections(
+        record: RecordSource,
+        sections: &[SectionRef<'a>],
+    ) -> Result<Self, Error> {
+        let regions: Vec<RegionRef> = sections
+            .iter()
+            .filter(|section| section.section_type == FW_ERROR_RECORD_GUID)
+            .filter_map(|section| {
+                let mut region = RegionRef::from_fer_slice(section.data)?;
+                region.source = Some(section.source.clone());
+                Some(region)
+            })
+            .collect();
+        if regions.is_empty() {
+            return Err(Error::NoCrashLogFound);
+        }
+
+        let mut crashlog = CrashLogRef::from_regions(regions)?;
+        crashlog.metadata.time = record.timestamp.clone();
+        crashlog.metadata.source = Some(record);
+        crashlog.metadata.platform = sections
+            .iter()
+            .filter(|section| section.section_type == SMBIOS_SECTION_GUID)
+            .find_map(|section| Platform::from_smbios_table(section.data));
+        Ok(crashlog)
+    }
+
+    /// Returns the register tree representation of the Crash Log record headers.
+    pub fn decode_without_cm(&self) -> Node {
+        let mut root = decode_metadata(
+            &self.metadata,
+            self.regions.iter().map(|region| region.source.as_ref()),
+        );
+        for region in self.regions.iter() {
+            for record in region.records.iter() {
+                root.merge(record.decode_without_cm())
+            }
+        }
+        root
+    }
+
+    /// Returns the register tree representation of the Crash Log record content.
+    #[cfg(feature = "collateral_manager")]
+    pub fn decode<T: CollateralTree>(&self, cm: &CollateralManager<T>) -> Node {
+        let mut root = decode_metadata(
+            &self.metadata,
+            self.regions.iter().map(|region| region.source.as_ref()),
+        );
+        for region in self.regions.iter() {
+            for record in region.records.iter() {
+                root.merge(record.decode(cm))
+            }
+        }
+        root
+    }
+
+    /// Returns the register tree representation of the Crash Log record content, limited to the
+    /// given `paths`. See [CrashLog::decode_paths].
+    #[cfg(feature = "collateral_manager")]
+    pub fn decode_paths<T: CollateralTree, S: AsRef<str>>(
+        &self,
+        cm: &CollateralManager<T>,
+        paths: &[S],
+    ) -> Node {
+        let filter = PathFilter::new(paths);
+        let mut root = decode_metadata(
+            &self.metadata,
+            self.regions.iter().map(|region| region.source.as_ref()),
+        );
+        for region in self.regions.iter() {
+            for record in region.records.iter() {
+                root.merge(record.decode_with_filter(cm, &filter))
+            }
+        }
+        root.retain(&filter);
+        root
+    }
+}
+
+impl From<CrashLogRef<'_>> for CrashLog {
+    fn from(crashlog: CrashLogRef<'_>) -> Self {
+        CrashLog {
+            regions: crashlog.regions.into_iter().map(Region::from).collect(),
+            metadata: crashlog.metadata,
+            ..CrashLog::default()
+        }
+    }
+}
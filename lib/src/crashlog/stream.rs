@@ -0,0 +1,288 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+//! Serialization of the register tree without keeping all the decoded records in memory.
+//!
+//! The register tree is serialized in two passes. The first pass merges the structure of the
+//! records down to the record nodes, the same way [Node::merge] does. As the content of a record
+//! node is never modified by the merge, it is only decoded during the second pass, right before
+//! being serialized.
+
+use super::CrashLog;
+use crate::Error;
+use crate::collateral::{CollateralManager, CollateralTree};
+use crate::node::{Node, NodeType, PathFilter};
+use crate::record::Record;
+use serde::ser::{Error as _, Serialize, SerializeMap, Serializer};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::io;
+
+/// Node of a register tree whose record nodes are decoded lazily
+enum Skeleton {
+    /// Node whose children can be merged with the ones of other records
+    Section(BTreeMap<String, Skeleton>),
+    /// Record or field node that has already been decoded
+    Node(Node),
+    /// Record or field node located at `path` in the register tree of a `record`
+    Deferred { record: usize, path: Vec<String> },
+}
+
+impl Skeleton {
+    fn from_node(node: Node) -> Self {
+        match node.kind {
+            NodeType::Record | NodeType::Field { .. } => Skeleton::Node(node),
+            _ => Skeleton::Section(
+                node.into_children()
+                    .map(|child| (child.name.clone(), Skeleton::from_node(child)))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Converts the structure of a `record`, as returned by [Record::decode_structure], into a
+    /// skeleton where the record nodes are deferred.
+    fn from_structure(node: Node, record: usize, path: &mut Vec<String>) -> Self {
+        if let NodeType::Record | NodeType::Field { .. } = node.kind {
+            return Skeleton::Deferred {
+                record,
+                path: path.clone(),
+            };
+        }
+
+        let mut children = BTreeMap::new();
+        for child in node.into_children() {
+            path.push(child.name.clone());
+            children.insert(
+                child.name.clone(),
+                Skeleton::from_structure(child, record, path),
+            );
+            path.pop();
+        }
+        Skeleton::Section(children)
+    }
+
+    /// Appends the paths to the nodes deferred in the skeleton to `paths`.
+    fn deferred_paths(&self, paths: &mut Vec<Vec<String>>) {
+        match self {
+            Skeleton::Section(children) => {
+                for child in children.values() {
+                    child.deferred_paths(paths)
+                }
+            }
+            Skeleton::Deferred { path, .. } => paths.push(path.clone()),
+            Skeleton::Node(_) => (),
+        }
+    }
+}
+
+/// Decodes the records of a [CrashLog] on demand.
+struct Decoder<'a, D, T: CollateralTree> {
+    records: Vec<&'a Record<D>>,
+    /// Selects the deferred nodes of each record
+    filters: Vec<PathFilter>,
+    cm: &'a CollateralManager<T>,
+    /// Register tree of the last decoded record
+    cache: RefCell<Option<(usize, Node)>>,
+}
+
+impl<D: AsRef<[u8]>, T: CollateralTree> Decoder<'_, D, T> {
+    fn decode(&self, record: usize, filter: &PathFilter) -> Node {
+        self.records[record].decode_with_filter(self.cm, filter)
+    }
+
+    /// Returns the register tree of the node located at `path` in the register tree of the
+    /// `record`.
+    fn take(&self, record: usize, path: &[String]) -> Option<Node> {
+        let mut node = self.decode(record, &PathFilter::from_segments(vec![path.to_vec()]));
+        let (name, parents) = path.split_last()?;
+        let mut parent = &mut node;
+        for parent_name in parents {
+            parent = parent.get_mut(parent_name)?;
+        }
+        parent.remove(name)
+    }
+
+    /// Merges the `other` skeleton into the `section`, following the rules of [Node::merge].
+    fn merge(&self, section: &mut BTreeMap<String, Skeleton>, other: BTreeMap<String, Skeleton>) {
+        for (name, child) in other {
+            match section.get_mut(&name) {
+                Some(Skeleton::Section(children)) => {
+                    let child = match child {
+                        Skeleton::Section(child) => child,
+                        Skeleton::Node(node) => self.children(node),
+                        Skeleton::Deferred { record, path } => self
+                            .take(record, &path)
+                            .map(|node| self.children(node))
+                            .unwrap_or_default(),
+                    };
+                    self.merge(children, child);
+                }
+                Some(_) => {
+                    let mut instance = 0;
+                    let mut instance_name = name.clone();
+                    while section.contains_key(&instance_name) {
+                        instance_name = format!("{name}{instance}");
+                        instance += 1;
+                    }
+                    section.insert(instance_name, child);
+                }
+                None => {
+                    section.insert(name, child);
+                }
+            }
+        }
+    }
+
+    fn children(&self, node: Node) -> BTreeMap<String, Skeleton> {
+        node.into_children()
+            .map(|child| (child.name.clone(), Skeleton::from_node(child)))
+            .collect()
+    }
+}
+
+/// Register tree of a [CrashLog] that decodes its records while being serialized
+struct Stream<'a, D, T: CollateralTree> {
+    root: Skeleton,
+    decoder: Decoder<'a, D, T>,
+}
+
+impl<'a, T: CollateralTree> Stream<'a, Vec<u8>, T> {
+    fn new(crashlog: &'a CrashLog, cm: &'a CollateralManager<T>) -> Self {
+        let mut decoder = Decoder {
+            records: crashlog
+                .regions
+                .iter()
+                .flat_map(|region| region.records.iter())
+                .collect(),
+            filters: Vec::new(),
+            cm,
+            cache: RefCell::new(None),
+        };
+
+        let mut root = BTreeMap::new();
+        for node in [crashlog.decode_cper_sections(), crashlog.decode_metadata()] {
+            if let Skeleton::Section(children) = Skeleton::from_node(node) {
+                decoder.merge(&mut root, children);
+            }
+        }
+
+        for (i, record) in decoder.records.iter().enumerate() {
+            let structure = record.decode_structure(cm);
+            let skeleton = Skeleton::from_structure(structure, i, &mut Vec::new());
+
+            let mut paths = Vec::new();
+            skeleton.deferred_paths(&mut paths);
+            decoder.filters.push(PathFilter::from_segments(paths));
+
+            if let Skeleton::Section(children) = skeleton {
+                decoder.merge(&mut root, children);
+            }
+        }
+
+        Self {
+            root: Skeleton::Section(root),
+            decoder,
+        }
+    }
+}
+
+/// Serializes a skeleton, decoding its deferred nodes
+struct Serialized<'s, 'a, D, T: CollateralTree> {
+    skeleton: &'s Skeleton,
+    decoder: &'s Decoder<'a, D, T>,
+}
+
+impl<D: AsRef<[u8]>, T: CollateralTree> Serialize for Serialized<'_, '_, D, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.skeleton {
+            Skeleton::Section(children) => {
+                let mut map = serializer.serialize_map(Some(children.len()))?;
+                for (name, skeleton) in children.iter() {
+                    map.serialize_entry(
+                        name,
+                        &Serialized {
+                            skeleton,
+                            decoder: self.decoder,
+                        },
+                    )?;
+                }
+                map.end()
+            }
+            Skeleton::Node(node) => node.serialize(serializer),
+            Skeleton::Deferred { record, path } => {
+                let mut cache = self.decoder.cache.borrow_mut();
+                if cache.as_ref().is_none_or(|(cached, _)| cached != record) {
+                    let node = self.decoder.decode(*record, &self.decoder.filters[*record]);
+                    *cache = Some((*record, node));
+                }
+
+                cache
+                    .as_ref()
+                    .and_then(|(_, node)| node.get_by_path(&path.join(".")))
+                    .ok_or_else(|| S::Error::custom("Record node is missing"))?
+                    .serialize(serializer)
+            }
+        }
+    }
+}
+
+impl<D: AsRef<[u8]>, T: CollateralTree> Serialize for Stream<'_, D, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(
+            "crashlog_data",
+            &Serialized {
+                skeleton: &self.root,
+                decoder: &self.decoder,
+            },
+        )?;
+        map.end()
+    }
+}
+
+impl CrashLog {
+    /// Decodes the content of the Crash Log records and writes the resulting register tree to
+    /// `writer` as pretty-printed JSON.
+    ///
+    /// The output is identical to the serialization of the [Node] returned by
+    /// [CrashLog::decode], but the register tree is never fully built in memory. Only the
+    /// structure of the tree and the content of a single record are kept in memory at any time,
+    /// which makes it suitable for very large captures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let data = std::fs::read("tests/samples/three_strike_timeout.crashlog").unwrap();
+    /// let crashlog = CrashLog::from_slice(&data).unwrap();
+    /// let cm = CollateralManager::embedded_tree().unwrap();
+    ///
+    /// let mut json = Vec::new();
+    /// crashlog.decode_to_writer(&cm, &mut json).unwrap();
+    /// assert_eq!(json, serde_json::to_vec_pretty(&crashlog.decode(&cm)).unwrap());
+    /// ```
+    pub fn decode_to_writer<T: CollateralTree, W: io::Write>(
+        &self,
+        cm: &CollateralManager<T>,
+        writer: W,
+    ) -> Result<(), Error> {
+        let stream = Stream::new(self, cm);
+        Ok(serde_json::to_writer_pretty(writer, &stream)?)
+    }
+
+    /// Decodes the content of several Crash Logs and writes the resulting register trees to
+    /// `writer` as a pretty-printed JSON array. See [CrashLog::decode_to_writer].
+    pub fn decode_all_to_writer<T: CollateralTree, W: io::Write>(
+        crashlogs: &[CrashLog],
+        cm: &CollateralManager<T>,
+        writer: W,
+    ) -> Result<(), Error> {
+        let streams = crashlogs
+            .iter()
+            .map(|crashlog| Stream::new(crashlog, cm))
+            .collect::<Vec<_>>();
+        Ok(serde_json::to_writer_pretty(writer, &streams)?)
+    }
+}
@@ -7,7 +7,7 @@ use crate::{
     header::Version,
 };
 #[cfg(not(feature = "std"))]
-use alloc::{fmt, str};
+use alloc::{fmt, str, string::String};
 #[cfg(not(feature = "std"))]
 use core::num;
 #[cfg(feature = "std")]
@@ -23,7 +23,12 @@ pub enum Error {
     MissingCollateral(PVSS, ItemPath),
     #[cfg(feature = "collateral_manager")]
     MissingDecodeDefinitions(Version),
+    #[cfg(feature = "collateral_manager")]
+    InvalidPVSS(String),
     InvalidBootErrorRecordRegion,
+    InvalidBootErrorRecordTable,
+    MissingBootErrorRecordRegion(u64, u32),
+    InvalidSmbiosTable,
     InvalidHeader,
     InvalidHeaderType(u16),
     InvalidRecordType(u8),
@@ -32,10 +37,17 @@ pub enum Error {
     JsonError(serde_json::Error),
     Utf8Error(str::Utf8Error),
     ParseIntError(num::ParseIntError),
+    InvalidDecodeDefinition(usize, num::ParseIntError),
     #[cfg(feature = "std")]
     IOError(io::Error),
     #[cfg(feature = "std")]
     OsStringError(std::ffi::OsString),
+    #[cfg(feature = "rasdaemon")]
+    SqliteError(rusqlite::Error),
+    #[cfg(feature = "archive_collateral_tree")]
+    ZipError(zip::result::ZipError),
+    #[cfg(feature = "http_collateral_tree")]
+    HttpError(Box<ureq::Error>),
 }
 
 #[cfg(feature = "std")]
@@ -55,7 +67,18 @@ impl fmt::Display for Error {
             Error::MissingDecodeDefinitions(version) => {
                 write!(f, "Missing decode definitions for {version}")
             }
+            #[cfg(feature = "collateral_manager")]
+            Error::InvalidPVSS(pvss) => write!(
+                f,
+                "Invalid PVSS: {pvss} (expected PRODUCT/VARIANT/STEPPING/SECURITY)"
+            ),
             Error::InvalidBootErrorRecordRegion => write!(f, "Invalid Boot Error Record region"),
+            Error::InvalidBootErrorRecordTable => write!(f, "Invalid Boot Error Record Table"),
+            Error::MissingBootErrorRecordRegion(address, length) => write!(
+                f,
+                "Missing Boot Error Record region (address: {address:#x}, length: {length:#x})"
+            ),
+            Error::InvalidSmbiosTable => write!(f, "Invalid SMBIOS structure table"),
             Error::InvalidHeader => write!(f, "Invalid Crash Log Header"),
             Error::InvalidHeaderType(ht) => write!(f, "Invalid Crash Log Header Type: {ht}"),
             Error::InvalidRecordType(rt) => write!(f, "Unknown Crash Log Record Type: {rt:#x}"),
@@ -64,10 +87,19 @@ impl fmt::Display for Error {
             Error::JsonError(err) => write!(f, "Invalid JSON file: {err}"),
             Error::Utf8Error(err) => write!(f, "UTF8 Error: {err}"),
             Error::ParseIntError(err) => write!(f, "Error while parsing integer: {err}"),
+            Error::InvalidDecodeDefinition(line, err) => {
+                write!(f, "Error while parsing integer at line {line}: {err}")
+            }
             #[cfg(feature = "std")]
             Error::IOError(err) => write!(f, "Encountered IO error: {err}"),
             #[cfg(feature = "std")]
             Error::OsStringError(s) => write!(f, "Cannot convert OS string: {s:?}"),
+            #[cfg(feature = "rasdaemon")]
+            Error::SqliteError(err) => write!(f, "SQLite error: {err}"),
+            #[cfg(feature = "archive_collateral_tree")]
+            Error::ZipError(err) => write!(f, "Invalid zip archive: {err}"),
+            #[cfg(feature = "http_collateral_tree")]
+            Error::HttpError(err) => write!(f, "HTTP error: {err}"),
         }
     }
 }
@@ -86,6 +118,27 @@ impl From<std::ffi::OsString> for Error {
     }
 }
 
+#[cfg(feature = "rasdaemon")]
+impl From<rusqlite::Error> for Error {
+    fn from(err: rusqlite::Error) -> Self {
+        Error::SqliteError(err)
+    }
+}
+
+#[cfg(feature = "archive_collateral_tree")]
+impl From<zip::result::ZipError> for Error {
+    fn from(err: zip::result::ZipError) -> Self {
+        Error::ZipError(err)
+    }
+}
+
+#[cfg(feature = "http_collateral_tree")]
+impl From<ureq::Error> for Error {
+    fn from(err: ureq::Error) -> Self {
+        Error::HttpError(Box::new(err))
+    }
+}
+
 #[cfg(feature = "serialize")]
 impl From<serde_json::Error> for Error {
     fn from(err: serde_json::Error) -> Self {
@@ -6,4 +6,6 @@ pub mod efi;
 #[cfg(all(target_family = "windows", feature = "std"))]
 pub mod event_log;
 #[cfg(all(target_os = "linux", feature = "std"))]
+pub mod pstore;
+#[cfg(all(target_os = "linux", feature = "std"))]
 pub mod sysfs;
@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: MIT
 
 use crate::bert::Bert;
+use crate::container::Container;
 use crate::metadata;
+use crate::smbios;
 use crate::{CrashLog, Error};
 use acpi::{AcpiHandler, AcpiTables, PhysicalMapping};
 use alloc::string::ToString;
@@ -73,6 +75,37 @@ pub fn find_bert() -> Result<Bert, Error> {
         })
 }
 
+fn find_platform() -> Option<metadata::Platform> {
+    uefi::system::with_config_table(|config_tables| {
+        for (guid, entry_point_size) in [
+            (uefi::table::cfg::SMBIOS3_GUID, 0x18),
+            (uefi::table::cfg::SMBIOS_GUID, 0x1F),
+        ] {
+            let Some(config_table) = config_tables.iter().find(|table| table.guid == guid) else {
+                continue;
+            };
+
+            let entry_point = unsafe {
+                core::slice::from_raw_parts(config_table.address as *const u8, entry_point_size)
+            };
+
+            let Some((address, size)) = smbios::table_location(entry_point) else {
+                log::warn!("Found invalid SMBIOS entry point.");
+                continue;
+            };
+
+            let table = unsafe { core::slice::from_raw_parts(address as *const u8, size) };
+            if let Some(platform) = metadata::Platform::from_smbios_table(table) {
+                log::info!("Found valid SMBIOS tables.");
+                return Some(platform);
+            }
+        }
+
+        log::warn!("Failed to find SMBIOS tables.");
+        None
+    })
+}
+
 pub(crate) fn get_crashlog_from_system_table(
     system_table: Option<NonNull<SystemTable>>,
 ) -> Result<CrashLog, Error> {
@@ -80,11 +113,10 @@ pub(crate) fn get_crashlog_from_system_table(
         unsafe { uefi::table::set_system_table(system_table.as_ptr()) }
     }
 
-    let mut crashlog = find_bert()
-        .and_then(|bert| {
-            unsafe { bert.berr_from_phys_mem() }.ok_or(Error::InvalidBootErrorRecordRegion)
-        })
-        .and_then(CrashLog::from_berr)?;
+    let table = find_bert()?;
+    let region =
+        unsafe { table.berr_from_phys_mem() }.ok_or(Error::InvalidBootErrorRecordRegion)?;
+    let mut crashlog = CrashLog::from_container(Container::Bert { table, region })?;
 
     crashlog.metadata = metadata::Metadata {
         computer: Some("efi".to_string()),
@@ -98,6 +130,8 @@ pub(crate) fn get_crashlog_from_system_table(
             })
             .inspect_err(|err| log::warn!("Cannot get time: {err}"))
             .ok(),
+        platform: find_platform(),
+        source: crashlog.metadata.source.take(),
     };
 
     Ok(crashlog)
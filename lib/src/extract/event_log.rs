@@ -160,6 +160,7 @@ fn metadata_from_evt_values(
             minute: time.wMinute as u8,
         }),
         computer: unsafe { computer.Anonymous.StringVal.to_string().ok() },
+        ..Default::default()
     })
 }
 
@@ -196,7 +197,11 @@ fn query_crashlogs(path: PCWSTR, query: PCWSTR, query_flags: u32) -> Result<Vec<
 
             match CrashLog::from_slice(binary) {
                 Ok(mut crashlog) => {
-                    crashlog.metadata = metadata_from_evt_values(values[1], values[2])?;
+                    crashlog.metadata = metadata::Metadata {
+                        platform: crashlog.metadata.platform.take(),
+                        source: crashlog.metadata.source.take(),
+                        ..metadata_from_evt_values(values[1], values[2])?
+                    };
                     crashlogs.push(crashlog)
                 }
                 Err(err) => {
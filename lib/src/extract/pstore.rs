@@ -0,0 +1,146 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+//! Reader for the error records persisted in the ACPI Error Record Serialization Table (ERST)
+//! and exposed by the Linux pstore file system.
+//!
+//! Only the kernels that expose the records created by the firmware (`unknown-erst-*` entries)
+//! allow the Crash Logs to be retrieved this way. More recent kernels skip these records.
+
+#[cfg(test)]
+mod tests;
+
+use crate::CrashLog;
+use crate::container::Container;
+use crate::cper::{
+    Cper, CperSection, CperSectionDescriptor, FW_ERROR_RECORD_GUID, RECORD_HEADER_SIZE,
+    SECTION_DESCRIPTOR_SIZE,
+};
+use crate::error::Error;
+use crate::region::Region;
+use std::path::Path;
+
+pub(crate) const PSTORE_PATH: &str = "/sys/fs/pstore";
+const ERST_BACKEND: &str = "-erst-";
+
+/// Returns the content of the Crash Log records as a list of byte slices. Used to compare the
+/// Crash Logs regardless of their container.
+fn record_data(crashlog: &CrashLog) -> Vec<&[u8]> {
+    crashlog
+        .regions
+        .iter()
+        .flat_map(|region| region.records.iter())
+        .map(|record| record.data.as_slice())
+        .collect()
+}
+
+/// Rebuilds the sections of an error record from the payload exposed by the ERST backend.
+///
+/// The ERST backend of the kernel strips the record header and the first section descriptor of
+/// the records that have not been created by pstore. The payload therefore starts with the
+/// descriptors of the other sections, followed by the content of all the sections. The first
+/// section is expected to be a Firmware Error Record.
+fn sections_from_payload(data: &[u8]) -> Option<Vec<CperSection>> {
+    // The offsets of the sections are relative to the beginning of the original record.
+    let stripped = RECORD_HEADER_SIZE + SECTION_DESCRIPTOR_SIZE;
+
+    let mut descriptors: Vec<CperSectionDescriptor> = Vec::new();
+    while let Some(descriptor) = data
+        .get(descriptors.len() * SECTION_DESCRIPTOR_SIZE..)
+        .and_then(CperSectionDescriptor::from_slice)
+    {
+        let offset = descriptor.section_offset as usize;
+        let end = offset + descriptor.section_length as usize;
+        if descriptor.section_length == 0
+            || offset < stripped + (descriptors.len() + 1) * SECTION_DESCRIPTOR_SIZE
+            || end > stripped + data.len()
+        {
+            break;
+        }
+        descriptors.push(descriptor);
+    }
+
+    let start = descriptors.len() * SECTION_DESCRIPTOR_SIZE;
+    let end = descriptors
+        .iter()
+        .map(|descriptor| descriptor.section_offset as usize - stripped)
+        .min()
+        .unwrap_or(data.len());
+    let mut sections = vec![CperSection::from_slice(
+        FW_ERROR_RECORD_GUID,
+        data.get(start..end)?,
+    )?];
+
+    for descriptor in descriptors {
+        let offset = descriptor.section_offset as usize - stripped;
+        let section = &data[offset..offset + descriptor.section_length as usize];
+        sections.extend(CperSection::from_slice(descriptor.section_type, section));
+    }
+
+    Some(sections)
+}
+
+/// Reads the Crash Log stored in a pstore entry.
+///
+/// The entry holds either the payload of a record created by the firmware (see
+/// [sections_from_payload]) or a complete CPER record.
+fn crashlog_from_entry(data: &[u8]) -> Result<CrashLog, Error> {
+    if let Some(cper) = Cper::from_slice(data) {
+        return CrashLog::from_container(Container::Cper(cper));
+    }
+
+    let regions = sections_from_payload(data)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(Region::from_cper_section)
+        .collect::<Vec<Region>>();
+    if regions.is_empty() {
+        return Err(Error::NoCrashLogFound);
+    }
+    CrashLog::from_regions(regions)
+}
+
+/// Reads the Crash Logs stored in the ERST entries of a pstore file system.
+///
+/// The Crash Logs whose records are identical to the ones of `exclude` (typically the Crash Log
+/// reported through the BERT) or of a previous entry are skipped.
+pub(crate) fn read_crashlogs_from_pstore(
+    path: &Path,
+    exclude: Option<&CrashLog>,
+) -> Result<Vec<CrashLog>, Error> {
+    let mut entries = Vec::new();
+    for entry in std::fs::read_dir(path)? {
+        let entry = entry?;
+        if entry.file_name().to_string_lossy().contains(ERST_BACKEND) {
+            entries.push(entry.path())
+        }
+    }
+    entries.sort();
+
+    let mut crashlogs: Vec<CrashLog> = Vec::new();
+    for entry in entries {
+        let data = std::fs::read(&entry)
+            .inspect_err(|err| log::warn!("Cannot read {}: {err}", entry.display()))
+            .unwrap_or_default();
+
+        let Ok(crashlog) = crashlog_from_entry(&data) else {
+            continue;
+        };
+
+        let records = record_data(&crashlog);
+        let duplicate = exclude
+            .into_iter()
+            .chain(crashlogs.iter())
+            .any(|other| record_data(other) == records);
+
+        if duplicate {
+            log::info!("Skipping duplicated Crash Log in {}", entry.display());
+            continue;
+        }
+
+        crashlogs.push(crashlog)
+    }
+
+    log::info!("Found {} Crash Logs in the ERST", crashlogs.len());
+    Ok(crashlogs)
+}
@@ -0,0 +1,85 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::{read_crashlogs_from_pstore, record_data};
+use crate::CrashLog;
+use crate::container::ContainerType;
+use crate::cper::{RECORD_HEADER_SIZE, SECTION_DESCRIPTOR_SIZE};
+
+fn create_pstore(name: &str, entries: &[(&str, &[u8])]) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(name);
+    let _ = std::fs::remove_dir_all(&path);
+    std::fs::create_dir(&path).unwrap();
+    for (name, data) in entries {
+        std::fs::write(path.join(name), data).unwrap();
+    }
+    path
+}
+
+#[test]
+fn crashlogs_from_pstore() {
+    let cper = std::fs::read("tests/samples/cper.whea").unwrap();
+
+    let path = create_pstore(
+        "iclg_pstore_test",
+        &[
+            ("dmesg-erst-7441709416124825601", b"Oops#1 Part1\nPanic"),
+            ("unknown-erst-7441709416124825602", &cper),
+            ("mce-ramoops-0", &cper),
+        ],
+    );
+
+    let crashlogs = read_crashlogs_from_pstore(&path, None).unwrap();
+    assert_eq!(crashlogs.len(), 1);
+    assert_eq!(crashlogs[0].regions.len(), 3);
+
+    let bert = CrashLog::from_slice(&cper).unwrap();
+    let crashlogs = read_crashlogs_from_pstore(&path, Some(&bert)).unwrap();
+    assert!(crashlogs.is_empty());
+
+    std::fs::remove_dir_all(&path).unwrap();
+}
+
+/// Strips the record header and the first section descriptor, as done by the ERST backend of the
+/// kernel for the records that have not been created by pstore.
+fn erst_payload(cper: &[u8]) -> &[u8] {
+    &cper[RECORD_HEADER_SIZE + SECTION_DESCRIPTOR_SIZE..]
+}
+
+#[test]
+fn crashlogs_from_erst_payload() {
+    // Record with several sections
+    let cper = std::fs::read("tests/samples/cper.whea").unwrap();
+    let expected = CrashLog::from_slice(&cper).unwrap();
+
+    // Record with a single section
+    let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let single = CrashLog::from_slice(&data).unwrap();
+    let single_cper = single.export(ContainerType::Cper);
+
+    let path = create_pstore(
+        "iclg_pstore_payload_test",
+        &[
+            ("dmesg-erst-7441709416124825601", b"Oops#1 Part1\nPanic"),
+            ("unknown-erst-7441709416124825602", erst_payload(&cper)),
+            (
+                "unknown-erst-7441709416124825603",
+                erst_payload(&single_cper),
+            ),
+        ],
+    );
+
+    let crashlogs = read_crashlogs_from_pstore(&path, None).unwrap();
+    assert_eq!(crashlogs.len(), 2);
+    assert_eq!(record_data(&crashlogs[0]), record_data(&expected));
+    assert_eq!(record_data(&crashlogs[1]), record_data(&single));
+
+    std::fs::remove_dir_all(&path).unwrap();
+}
+
+#[test]
+fn empty_pstore() {
+    let path = create_pstore("iclg_empty_pstore_test", &[]);
+    assert!(read_crashlogs_from_pstore(&path, None).unwrap().is_empty());
+    std::fs::remove_dir_all(&path).unwrap();
+}
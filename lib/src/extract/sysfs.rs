@@ -1,16 +1,41 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
-use crate::bert::Berr;
+use crate::bert::{Berr, Bert};
+use crate::container::Container;
 use crate::error::Error;
+use crate::metadata::Platform;
 
+const BERT_PATH: &str = "/sys/firmware/acpi/tables/BERT";
 const BERR_PATH: &str = "/sys/firmware/acpi/tables/data/BERT";
+const DMI_PATH: &str = "/sys/firmware/dmi/tables/DMI";
 
-pub(crate) fn read_berr_from_sysfs() -> Result<Berr, Error> {
+fn read_berr_from_sysfs() -> Result<Berr, Error> {
     std::fs::read(BERR_PATH)
         .map_err(|err| match err.kind() {
             std::io::ErrorKind::NotFound => Error::NoCrashLogFound,
             _ => Error::from(err),
         })
-        .and_then(|berr| Berr::from_slice(&berr).ok_or(Error::InvalidBootErrorRecordRegion))
+        .and_then(|berr| {
+            Berr::from_region(&berr, Some(berr.len())).ok_or(Error::InvalidBootErrorRecordRegion)
+        })
+}
+
+/// Reads the BERT and its Boot Error Region.
+///
+/// A new table is built if the original one cannot be read.
+pub(crate) fn read_bert_from_sysfs() -> Result<Container, Error> {
+    let region = read_berr_from_sysfs()?;
+    let table = std::fs::read(BERT_PATH)
+        .inspect_err(|err| log::warn!("Cannot read the BERT: {err}"))
+        .ok()
+        .and_then(|table| Bert::from_slice(&table))
+        .unwrap_or_else(|| Bert::new(0, region.to_bytes().len() as u32));
+    Ok(Container::Bert { table, region })
+}
+
+pub(crate) fn read_platform_from_sysfs() -> Result<Platform, Error> {
+    std::fs::read(DMI_PATH)
+        .map_err(Error::from)
+        .and_then(|dmi| Platform::from_smbios_table(&dmi).ok_or(Error::InvalidSmbiosTable))
 }
@@ -9,7 +9,11 @@
 mod tests;
 
 #[cfg(feature = "embedded_collateral_tree")]
-use crate::collateral::{CollateralManager, EmbeddedTree};
+use crate::Error;
+#[cfg(feature = "embedded_collateral_tree")]
+use crate::collateral::{
+    CollateralManager, CollateralTree, EmbeddedTree, ItemPath, MemoryTree, PVSS,
+};
 use crate::crashlog::CrashLog;
 use crate::node::{Node, NodeChildren, NodeType};
 #[cfg(not(feature = "std"))]
@@ -18,6 +22,7 @@ use alloc::{
     collections::VecDeque,
     ffi::CString,
     string::{String, ToString},
+    vec::Vec,
 };
 use core::slice;
 #[cfg(any(all(target_os = "uefi", feature = "extraction"), doc))]
@@ -43,7 +48,55 @@ use uefi_raw::table::system::SystemTable;
 /// [`crashlog_deinit`] function.
 pub struct CrashLogContext {
     #[cfg(feature = "embedded_collateral_tree")]
-    collateral_manager: CollateralManager<EmbeddedTree>,
+    collateral_manager: CollateralManager<ContextTree>,
+}
+
+/// Collateral tree of the [`CrashLogContext`].
+///
+/// The items registered using [`crashlog_register_collateral`] are stored on top of the embedded
+/// collateral tree, such that they can be inserted in place.
+#[cfg(feature = "embedded_collateral_tree")]
+struct ContextTree {
+    registered: MemoryTree,
+    embedded: EmbeddedTree,
+}
+
+#[cfg(feature = "embedded_collateral_tree")]
+impl ContextTree {
+    fn new() -> Self {
+        Self {
+            registered: MemoryTree::new(),
+            embedded: EmbeddedTree::new(),
+        }
+    }
+}
+
+#[cfg(feature = "embedded_collateral_tree")]
+impl CollateralTree for ContextTree {
+    fn get(&self, pvss: &PVSS, item: &ItemPath) -> Result<Vec<u8>, Error> {
+        match self.registered.get(pvss, item) {
+            Err(Error::MissingCollateral(..)) => self.embedded.get(pvss, item),
+            result => result,
+        }
+    }
+
+    fn search(&self, item: &ItemPath) -> Result<Vec<PVSS>, Error> {
+        let mut hits = self.embedded.search(item)?;
+        for pvss in self.registered.search(item)? {
+            hits.retain(|hit| *hit != pvss);
+            hits.push(pvss);
+        }
+        Ok(hits)
+    }
+
+    fn list(&self, path: &ItemPath) -> Result<Vec<(PVSS, ItemPath)>, Error> {
+        let mut items = self.embedded.list(path)?;
+        for item in self.registered.list(path)? {
+            items.retain(|registered| *registered != item);
+            items.push(item);
+        }
+        Ok(items)
+    }
 }
 
 /// Opaque type that represents an iterator over Crash Logs.
@@ -97,7 +150,7 @@ pub extern "C" fn crashlog_init() -> *mut CrashLogContext {
 
     #[cfg(feature = "embedded_collateral_tree")]
     {
-        if let Ok(collateral_manager) = CollateralManager::embedded_tree() {
+        if let Ok(collateral_manager) = CollateralManager::new(ContextTree::new()) {
             alloc(CrashLogContext { collateral_manager })
         } else {
             ptr::null_mut()
@@ -105,6 +158,60 @@ pub extern "C" fn crashlog_init() -> *mut CrashLogContext {
     }
 }
 
+/// Registers a collateral item, such as a decode definition or a target info file, in the
+/// [`CrashLogContext`].
+///
+/// The registered items take precedence over the ones embedded in the library. Registering an
+/// item that already exists replaces its content.
+///
+/// # Safety
+///
+/// This must be called with a pointer to a [`CrashLogContext`] that was earlier obtained by
+/// calling the [`crashlog_init`] function.
+///
+/// The `pvss` and `path` arguments must be valid nul-terminated strings. The `pvss` must be
+/// formatted as `PRODUCT/VARIANT/STEPPING/SECURITY` and the `path` is relative to the `crashlog`
+/// directory of the product (e.g. `decode-defs/MCA/1/layout.csv`).
+///
+/// The `data` pointer must point to a valid memory region that contains `size` bytes.
+///
+/// # Errors
+///
+/// Returns `false` if one of the arguments is `NULL` or invalid.
+#[cfg(feature = "embedded_collateral_tree")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crashlog_register_collateral(
+    context: *mut CrashLogContext,
+    pvss: *const c_char,
+    path: *const c_char,
+    data: *const u8,
+    size: usize,
+) -> bool {
+    if context.is_null() || pvss.is_null() || path.is_null() || data.is_null() {
+        return false;
+    }
+
+    let context = unsafe { &mut *context };
+    let Ok(pvss) = unsafe { CStr::from_ptr(pvss) }
+        .to_str()
+        .map_err(Error::from)
+        .and_then(str::parse::<PVSS>)
+    else {
+        return false;
+    };
+    let Ok(path) = unsafe { CStr::from_ptr(path) }.to_str() else {
+        return false;
+    };
+    let data = unsafe { slice::from_raw_parts(data, size) };
+
+    context
+        .collateral_manager
+        .update_tree(|tree| {
+            tree.registered.insert(pvss, path, data);
+        })
+        .is_ok()
+}
+
 /// Creates a [`CrashLog`] object from a binary blob.
 ///
 /// The binary blob pointed by the `data` argument can be a raw Crash Log region, a BERT
@@ -248,7 +355,7 @@ pub unsafe extern "C" fn crashlog_decode(
     let crashlog = unsafe { &*crashlog };
     #[cfg(feature = "embedded_collateral_tree")]
     {
-        alloc(crashlog.decode(&mut context.collateral_manager))
+        alloc(crashlog.decode(&context.collateral_manager))
     }
     #[cfg(not(feature = "embedded_collateral_tree"))]
     {
@@ -61,8 +61,8 @@ fn ffi() {
 fn ffi_json() {
     let blob = std::fs::read("tests/samples/dummy.bert").unwrap();
     let crashlog = CrashLog::from_slice(&blob).unwrap();
-    let mut cm = CollateralManager::embedded_tree().unwrap();
-    let root = crashlog.decode(&mut cm);
+    let cm = CollateralManager::embedded_tree().unwrap();
+    let root = crashlog.decode(&cm);
     let reference = serde_json::to_string(&root).unwrap();
 
     unsafe {
@@ -116,3 +116,49 @@ fn ffi_export() {
         crashlog_deinit(ctx);
     }
 }
+
+#[test]
+fn register_collateral() {
+    let blob = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let layout = b"name;offset;size\nmca;0;64\nmca.foo;32;8";
+    let ctx = crashlog_init();
+    assert_ne!(ctx, std::ptr::null_mut());
+
+    unsafe {
+        let crashlog = crashlog_read_from_buffer(ctx, blob.as_ptr(), blob.len());
+        assert_ne!(crashlog, std::ptr::null_mut());
+
+        let root = crashlog_decode(ctx, crashlog);
+        assert_eq!(
+            crashlog_get_node_by_path(ctx, root, c"mca.foo".as_ptr()),
+            std::ptr::null()
+        );
+        crashlog_release_nodes(root);
+
+        assert!(!crashlog_register_collateral(
+            ctx,
+            c"XYZ/all".as_ptr(),
+            c"decode-defs/MCA/1/layout.csv".as_ptr(),
+            layout.as_ptr(),
+            layout.len(),
+        ));
+        assert!(crashlog_register_collateral(
+            ctx,
+            c"XYZ/all/all/all".as_ptr(),
+            c"decode-defs/MCA/1/layout.csv".as_ptr(),
+            layout.as_ptr(),
+            layout.len(),
+        ));
+
+        let root = crashlog_decode(ctx, crashlog);
+        let foo = crashlog_get_node_by_path(ctx, root, c"mca.foo".as_ptr());
+        assert_ne!(foo, std::ptr::null());
+        let mut value: u64 = 0;
+        assert!(crashlog_get_node_value(ctx, foo, &mut value));
+        assert_eq!(value, blob[4] as u64);
+
+        crashlog_release_nodes(root);
+        crashlog_release(crashlog);
+        crashlog_deinit(ctx);
+    }
+}
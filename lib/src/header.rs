@@ -468,7 +468,8 @@ impl Version {
             | self.revision
     }
 
-    fn record_type_as_str(&self) -> Result<&'static str, Error> {
+    /// Returns the name of the record type, as used in the collateral tree.
+    pub(crate) fn record_type_as_str(&self) -> Result<&'static str, Error> {
         Ok(match self.record_type {
             record_types::PMC => "PMC",
             record_types::PMC_FW_TRACE => "PMC_FW_Trace",
@@ -486,6 +487,16 @@ impl Version {
             rt => return Err(Error::InvalidRecordType(rt)),
         })
     }
+
+    /// Returns the names of the decode definitions that define the payload of the record.
+    #[cfg(feature = "collateral_manager")]
+    pub(crate) fn decode_definitions(&self) -> &'static [&'static str] {
+        if let record_types::PCORE | record_types::ECORE = self.record_type {
+            &["layout_thread.csv", "layout_core.csv"]
+        } else {
+            &["layout.csv"]
+        }
+    }
 }
 
 impl fmt::Display for Version {
@@ -0,0 +1,11 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+//! Reconstruction of Crash Logs from the output of third-party tools.
+
+pub mod acpidump;
+#[cfg(feature = "serialize")]
+pub mod crashdump;
+pub mod kernel_log;
+#[cfg(feature = "rasdaemon")]
+pub mod rasdaemon;
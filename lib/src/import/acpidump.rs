@@ -0,0 +1,134 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+//! Parser for the ACPI tables dumped by `acpidump` and `acpixtract`.
+//!
+//! `acpidump` prints each table as a hex dump preceded by its signature and address:
+//!
+//! ```text
+//! BERT @ 0x0000000000000000
+//!     0000: 42 45 52 54 30 00 00 00 01 34 49 4E 54 45 4C 20  BERT0....4INTEL
+//!     0010: 20 20 20 20 20 20 20 20 01 00 00 00 20 20 20 20          ....
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+use crate::CrashLog;
+use crate::bert::{Berr, Bert};
+use crate::container::Container;
+use crate::error::Error;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Parses a line of an `acpidump` table dump.
+///
+/// Returns the offset of the line and the bytes it contains.
+fn parse_dump_line(line: &str) -> Option<(usize, Vec<u8>)> {
+    let (offset, dump) = line.trim_start().split_once(": ")?;
+    let offset = usize::from_str_radix(offset, 16).ok()?;
+
+    let hex = dump.split("  ").next()?;
+    let bytes = hex
+        .split(' ')
+        .map(|byte| {
+            if byte.len() != 2 {
+                return None;
+            }
+            u8::from_str_radix(byte, 16).ok()
+        })
+        .collect::<Option<Vec<u8>>>()?;
+
+    Some((offset, bytes))
+}
+
+/// Extracts the table with the given `signature` from the text output of `acpidump`.
+fn find_table(dump: &str, signature: &str) -> Option<Vec<u8>> {
+    let mut table: Option<Vec<u8>> = None;
+
+    for line in dump.lines() {
+        if let Some((name, _)) = line.split_once(" @ ") {
+            if table.is_some() {
+                break;
+            }
+            if name.trim() == signature {
+                table = Some(Vec::new());
+            }
+            continue;
+        }
+
+        let Some(table) = table.as_mut() else {
+            continue;
+        };
+
+        if let Some((offset, bytes)) = parse_dump_line(line) {
+            if offset != table.len() {
+                log::warn!("Discontinuous {signature} dump at offset {offset:#x}");
+                table.resize(offset, 0);
+            }
+            table.extend_from_slice(&bytes);
+        }
+    }
+
+    table
+}
+
+/// Reads the [Bert] from either the text output of `acpidump` or a binary table extracted by
+/// `acpixtract`.
+fn read_bert(tables: &[u8]) -> Result<Bert, Error> {
+    // The text output of `acpidump -n BERT` also starts with the signature of the table.
+    let text = tables.get(4..).is_some_and(|s| s.starts_with(b" @ 0x"));
+    let table = if tables.starts_with(b"BERT") && !text {
+        Vec::from(tables)
+    } else {
+        find_table(core::str::from_utf8(tables)?, "BERT").ok_or(Error::NoCrashLogFound)?
+    };
+
+    let length = table.len().min(
+        table
+            .get(4..8)
+            .and_then(|length| length.try_into().ok())
+            .map(u32::from_le_bytes)
+            .unwrap_or_default() as usize,
+    );
+    let checksum = table[..length]
+        .iter()
+        .fold(0u8, |acc, byte| acc.wrapping_add(*byte));
+    if checksum != 0 {
+        log::warn!("Invalid BERT checksum");
+    }
+
+    Bert::from_slice(&table).ok_or(Error::InvalidBootErrorRecordTable)
+}
+
+/// Rebuilds the Crash Log from an ACPI table dump and a dump of the Boot Error Region.
+///
+/// Returns [Error::MissingBootErrorRecordRegion] with the location of the region if `region` is
+/// not provided.
+pub(crate) fn crashlog_from_acpi_dump(
+    tables: &[u8],
+    region: Option<&[u8]>,
+) -> Result<CrashLog, Error> {
+    let bert = read_bert(tables)?;
+    let (address, length) = (bert.region, bert.region_length);
+    log::info!("Boot Error Region located at {address:#x} (length: {length:#x})");
+
+    let Some(region) = region else {
+        return Err(Error::MissingBootErrorRecordRegion(address, length));
+    };
+
+    let region = region.get(..length as usize).unwrap_or_else(|| {
+        log::warn!(
+            "The Boot Error Region dump is truncated ({:#x} bytes instead of {length:#x})",
+            region.len()
+        );
+        region
+    });
+
+    let region =
+        Berr::from_region(region, Some(region.len())).ok_or(Error::InvalidBootErrorRecordRegion)?;
+    CrashLog::from_container(Container::Bert {
+        table: bert,
+        region,
+    })
+}
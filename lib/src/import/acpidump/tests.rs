@@ -0,0 +1,75 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::{find_table, parse_dump_line};
+use crate::CrashLog;
+use crate::error::Error;
+
+#[test]
+fn dump_line() {
+    assert_eq!(
+        parse_dump_line("    0030: 00 01 FF                 ..."),
+        Some((0x30, vec![0x00, 0x01, 0xff]))
+    );
+    assert_eq!(parse_dump_line("BERT @ 0x000000006f6a7000"), None);
+}
+
+#[test]
+fn bert_from_acpidump() {
+    let dump = std::fs::read_to_string("tests/samples/acpidump.txt").unwrap();
+    let bert = find_table(&dump, "BERT").unwrap();
+    assert_eq!(bert.len(), 48);
+    assert!(bert.starts_with(b"BERT"));
+    assert!(find_table(&dump, "DSDT").is_none());
+}
+
+#[test]
+fn crashlog_from_acpidump() {
+    let dump = std::fs::read("tests/samples/acpidump.txt").unwrap();
+    let bert = std::fs::read("tests/samples/dummy.bert").unwrap();
+
+    let crashlog = CrashLog::from_acpi_dump(&dump, Some(&bert[48..])).unwrap();
+    assert_eq!(crashlog.regions.len(), 2);
+
+    let crashlog = CrashLog::from_acpi_dump(&bert[..48], Some(&bert[48..])).unwrap();
+    assert_eq!(crashlog.regions.len(), 2);
+}
+
+#[test]
+fn bert_first_in_acpidump() {
+    let dump = std::fs::read_to_string("tests/samples/acpidump.txt").unwrap();
+    let bert: String = dump
+        .lines()
+        .skip_while(|line| !line.starts_with("BERT @ "))
+        .take_while(|line| !line.is_empty())
+        .map(|line| format!("{line}\n"))
+        .collect();
+    assert!(bert.starts_with("BERT @ 0x"));
+
+    assert!(matches!(
+        CrashLog::from_acpi_dump(bert.as_bytes(), None),
+        Err(Error::MissingBootErrorRecordRegion(0x6f6a9018, 0x44c))
+    ));
+}
+
+#[test]
+fn invalid_table() {
+    assert!(matches!(
+        CrashLog::from_acpi_dump(b"BERT\x08\x00\x00\x00", None),
+        Err(Error::InvalidBootErrorRecordTable)
+    ));
+}
+
+#[test]
+fn missing_region() {
+    let dump = std::fs::read("tests/samples/acpidump.txt").unwrap();
+    assert!(matches!(
+        CrashLog::from_acpi_dump(&dump, None),
+        Err(Error::MissingBootErrorRecordRegion(0x6f6a9018, 0x44c))
+    ));
+
+    assert!(matches!(
+        CrashLog::from_acpi_dump(b"HPET @ 0x0\n", None),
+        Err(Error::NoCrashLogFound)
+    ));
+}
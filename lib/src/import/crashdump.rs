@@ -0,0 +1,148 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+//! Parser for the crashdump files collected out-of-band by the OpenBMC `crashdump` service.
+//!
+//! The Crash Log data is located in the `crashlog` section of each processor:
+//!
+//! ```json
+//! {
+//!   "crash_data": {
+//!     "METADATA": { "timestamp": "2025-01-31T12:34:56Z", ... },
+//!     "PROCESSORS": {
+//!       "cpu0": {
+//!         "crashlog": {
+//!           "die0": { "agent_mca": ["0x00000001", "0x00000002", ...] },
+//!           ...
+//!         },
+//!         ...
+//!       },
+//!       ...
+//!     }
+//!   }
+//! }
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+use crate::CrashLog;
+use crate::error::Error;
+use crate::metadata::{Metadata, Platform, Time};
+use crate::region::Region;
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+use serde_json::{Map, Value};
+
+/// Parses the numeric suffix of a key of the crashdump (`cpu0`, `die1`, ...).
+fn parse_index(key: &str, prefix: &str) -> Option<u8> {
+    key.strip_prefix(prefix)?
+        .trim_start_matches('_')
+        .parse()
+        .ok()
+}
+
+/// Converts an array of hexadecimal strings into bytes.
+///
+/// The values are stored as little-endian dwords unless one of them is wider than 32 bits, in
+/// which case all the values are stored as qwords.
+fn parse_blob(values: &[Value]) -> Option<Vec<u8>> {
+    let values = values
+        .iter()
+        .map(|value| {
+            let hex = value.as_str()?;
+            let hex = hex.strip_prefix("0x").unwrap_or(hex);
+            Some((hex.len(), u64::from_str_radix(hex, 16).ok()?))
+        })
+        .collect::<Option<Vec<(usize, u64)>>>()?;
+
+    let width = if values.iter().any(|(len, _)| *len > 8) {
+        8
+    } else {
+        4
+    };
+
+    Some(
+        values
+            .iter()
+            .flat_map(|(_, value)| value.to_le_bytes().into_iter().take(width))
+            .collect(),
+    )
+}
+
+/// Collects the Crash Log regions stored in the `crashlog` section of a processor.
+fn collect_regions(
+    section: &Map<String, Value>,
+    socket_id: u8,
+    die_id: Option<u8>,
+    regions: &mut Vec<Region>,
+) {
+    for (key, value) in section.iter() {
+        match value {
+            Value::Object(object) => {
+                let die_id = parse_index(key, "die").or(die_id);
+                collect_regions(object, socket_id, die_id, regions)
+            }
+            Value::Array(values) => {
+                let Some(blob) = parse_blob(values) else {
+                    log::warn!("Invalid Crash Log data in cpu{socket_id}.{key}");
+                    continue;
+                };
+
+                match Region::from_slice(&blob) {
+                    Ok(mut region) => {
+                        for record in region.records.iter_mut() {
+                            record.context.socket_id = Some(socket_id);
+                            record.context.die_id = die_id;
+                        }
+                        regions.push(region)
+                    }
+                    Err(err) => {
+                        log::warn!("Invalid Crash Log region in cpu{socket_id}.{key}: {err}")
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+/// Rebuilds the Crash Log stored in an OpenBMC crashdump file.
+pub(crate) fn crashlog_from_crashdump(s: &[u8]) -> Result<CrashLog, Error> {
+    let crashdump: Value = serde_json::from_slice(s)?;
+    let crash_data = crashdump.get("crash_data").unwrap_or(&crashdump);
+
+    let mut regions = Vec::new();
+    if let Some(processors) = crash_data.get("PROCESSORS").and_then(Value::as_object) {
+        for (key, processor) in processors.iter() {
+            let Some(socket_id) = parse_index(key, "cpu") else {
+                continue;
+            };
+
+            if let Some(section) = processor.get("crashlog").and_then(Value::as_object) {
+                collect_regions(section, socket_id, None, &mut regions)
+            }
+        }
+    }
+
+    if regions.is_empty() {
+        return Err(Error::NoCrashLogFound);
+    }
+
+    let mut crashlog = CrashLog::from_regions(regions)?;
+    crashlog.metadata = Metadata {
+        time: crash_data
+            .pointer("/METADATA/timestamp")
+            .and_then(Value::as_str)
+            .and_then(Time::from_iso8601),
+        platform: crash_data
+            .pointer("/METADATA/bios_id")
+            .and_then(Value::as_str)
+            .map(|bios_id| Platform {
+                bios_version: Some(bios_id.into()),
+                ..Platform::default()
+            }),
+        ..Metadata::default()
+    };
+    Ok(crashlog)
+}
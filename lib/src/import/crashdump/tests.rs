@@ -0,0 +1,60 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::{parse_blob, parse_index};
+use crate::CrashLog;
+use serde_json::json;
+
+#[test]
+fn index() {
+    assert_eq!(parse_index("cpu1", "cpu"), Some(1));
+    assert_eq!(parse_index("die_2", "die"), Some(2));
+    assert_eq!(parse_index("agent_mca", "die"), None);
+}
+
+#[test]
+fn blob() {
+    let values = json!(["0x04030201", "0x8"]);
+    assert_eq!(
+        parse_blob(values.as_array().unwrap()),
+        Some(vec![1, 2, 3, 4, 8, 0, 0, 0])
+    );
+
+    let values = json!(["0x0807060504030201", "0x0"]);
+    assert_eq!(
+        parse_blob(values.as_array().unwrap()),
+        Some(vec![1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 0, 0, 0, 0])
+    );
+
+    let values = json!(["0x1", 2]);
+    assert_eq!(parse_blob(values.as_array().unwrap()), None);
+}
+
+#[test]
+fn crashlog_from_crashdump() {
+    let crashdump = std::fs::read("tests/samples/openbmc_crashdump.json").unwrap();
+    let crashlog = CrashLog::from_openbmc_crashdump(&crashdump).unwrap();
+    assert_eq!(crashlog.regions.len(), 2);
+
+    let record = &crashlog.regions[0].records[0];
+    assert_eq!(record.header.record_type().unwrap(), "MCA");
+    assert_eq!(record.context.socket_id, Some(0));
+    assert_eq!(record.context.die_id, Some(0));
+
+    let record = &crashlog.regions[1].records[0];
+    assert_eq!(record.header.record_type().unwrap(), "CRASHLOG_AGENT");
+    assert_eq!(record.context.socket_id, Some(1));
+    assert_eq!(record.context.die_id, Some(2));
+
+    assert_eq!(crashlog.metadata.to_string(), "2026-10-16-22-05");
+    assert_eq!(
+        crashlog.metadata.platform.unwrap().bios_version.as_deref(),
+        Some("EGSDCRB1.SYS.0105.D74")
+    );
+}
+
+#[test]
+fn no_crashlog_in_crashdump() {
+    let crashdump = json!({"crash_data": {"PROCESSORS": {"cpu0": {"big_core": {}}}}});
+    assert!(CrashLog::from_openbmc_crashdump(crashdump.to_string().as_bytes()).is_err());
+}
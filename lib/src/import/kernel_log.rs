@@ -0,0 +1,264 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+//! Parser for the hardware errors printed by the BERT and GHES drivers of the Linux kernel.
+
+#[cfg(test)]
+mod tests;
+
+use crate::CrashLog;
+use crate::cper::CperSection;
+use crate::cper::fer::{FirmwareErrorRecord, FirmwareErrorRecordHeader};
+use crate::error::Error;
+use crate::metadata::{Metadata, Time};
+use crate::region::Region;
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+use uguid::Guid;
+
+const HW_ERR: &str = "[Hardware Error]:";
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+enum SectionType {
+    /// `section_type: Firmware Error Record Reference`
+    FirmwareErrorRecord,
+    /// `section type: unknown, <guid>`
+    Raw(Guid),
+    Other,
+}
+
+struct Section {
+    section_type: SectionType,
+    header: FirmwareErrorRecordHeader,
+    data: Vec<u8>,
+}
+
+impl Section {
+    fn new() -> Self {
+        Section {
+            section_type: SectionType::Other,
+            header: FirmwareErrorRecordHeader::default(),
+            data: Vec::new(),
+        }
+    }
+
+    fn cper_section(self) -> Option<CperSection> {
+        match self.section_type {
+            SectionType::FirmwareErrorRecord => {
+                Some(CperSection::FirmwareErrorRecord(FirmwareErrorRecord {
+                    header: self.header,
+                    payload: self.data,
+                }))
+            }
+            SectionType::Raw(guid) => CperSection::from_slice(guid, &self.data),
+            SectionType::Other => None,
+        }
+    }
+
+    fn parse_line(&mut self, message: &str) {
+        if message.starts_with("section_type: Firmware Error Record Reference") {
+            self.section_type = SectionType::FirmwareErrorRecord;
+        } else if let Some(guid) = message.strip_prefix("section type: unknown, ") {
+            match Guid::try_parse(guid.trim()) {
+                Ok(guid) => self.section_type = SectionType::Raw(guid),
+                Err(_) => log::warn!("Invalid section type: {guid}"),
+            }
+        } else if let Some(dump) = parse_hex_dump_line(message) {
+            self.push_hex_dump_line(dump)
+        } else if let SectionType::FirmwareErrorRecord = self.section_type {
+            self.parse_firmware_error_record_line(message)
+        }
+    }
+
+    fn parse_firmware_error_record_line(&mut self, message: &str) {
+        let Some((key, value)) = message.split_once(": ") else {
+            return;
+        };
+        let value = value.trim();
+
+        match key {
+            "Firmware Error Record Type" => {
+                self.header.error_type = if value.starts_with("IPF SAL") {
+                    0
+                } else if value.contains("Type1") {
+                    1
+                } else {
+                    2
+                }
+            }
+            "Revision" => self.header.revision = value.parse().unwrap_or_default(),
+            // The kernel prints the 64-bit identifier for revision 0 and the GUID for revision 2.
+            "Record Identifier" if self.header.revision >= 2 => {
+                self.header.guid = Guid::try_parse(value).unwrap_or_else(|_| {
+                    log::warn!("Invalid Firmware Error Record GUID: {value}");
+                    Guid::ZERO
+                })
+            }
+            "Record Identifier" => {
+                self.header.record_identifier = u64::from_str_radix(value, 16).unwrap_or_default()
+            }
+            _ => (),
+        }
+    }
+
+    fn push_hex_dump_line(&mut self, (offset, bytes): (usize, Vec<u8>)) {
+        if offset != self.data.len() {
+            log::warn!(
+                "Discontinuous hex dump: expected offset {:#x}, found {offset:#x}",
+                self.data.len()
+            );
+            self.data.resize(offset, 0);
+        }
+        self.data.extend_from_slice(&bytes);
+    }
+}
+
+struct Event {
+    metadata: Metadata,
+    sections: Vec<Section>,
+}
+
+impl Event {
+    fn into_crashlog(self) -> Option<CrashLog> {
+        let regions: Vec<Region> = self
+            .sections
+            .into_iter()
+            .filter_map(Section::cper_section)
+            .filter_map(|section| Region::from_cper_section(&section))
+            .collect();
+
+        if regions.is_empty() {
+            return None;
+        }
+
+        CrashLog::from_regions(regions)
+            .inspect_err(|err| log::warn!("Cannot rebuild Crash Log from kernel log: {err}"))
+            .ok()
+            .map(|crashlog| CrashLog {
+                metadata: self.metadata,
+                ..crashlog
+            })
+    }
+}
+
+/// Parses a line printed by `print_hex_dump()` with `DUMP_PREFIX_OFFSET`.
+///
+/// Returns the offset of the line and the bytes it contains. The values are printed as
+/// little-endian groups of 1, 2, 4, or 8 bytes followed by an optional ASCII column.
+fn parse_hex_dump_line(message: &str) -> Option<(usize, Vec<u8>)> {
+    let (offset, dump) = message.split_once(": ")?;
+    if offset.len() != 8 {
+        return None;
+    }
+    let offset = usize::from_str_radix(offset, 16).ok()?;
+
+    let mut bytes = Vec::new();
+    let hex = dump.split("  ").next()?;
+    for group in hex.split(' ') {
+        if group.is_empty() || group.len() % 2 != 0 || group.len() > 16 {
+            return None;
+        }
+        let value = u64::from_str_radix(group, 16).ok()?;
+        bytes.extend_from_slice(&value.to_le_bytes()[..group.len() / 2]);
+    }
+
+    Some((offset, bytes))
+}
+
+fn parse_month(month: &str) -> Option<u8> {
+    MONTHS
+        .iter()
+        .position(|m| *m == month)
+        .map(|index| index as u8 + 1)
+}
+
+/// Parses a `ctime()` timestamp (`dmesg -T`): `Fri Jan 31 12:34:56 2025`.
+fn parse_ctime(s: &str) -> Option<Time> {
+    let mut tokens = s.split_whitespace().skip(1);
+    let month = parse_month(tokens.next()?)?;
+    let day = tokens.next()?.parse().ok()?;
+    let mut time = tokens.next()?.split(':');
+    let hour = time.next()?.parse().ok()?;
+    let minute = time.next()?.parse().ok()?;
+    let year = tokens.next()?.parse().ok()?;
+
+    Some(Time {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+    })
+}
+
+/// Extracts the time and host name from the prefix added to the kernel messages by `dmesg`,
+/// `journalctl`, or `syslog`.
+fn parse_prefix(prefix: &str) -> Metadata {
+    let mut metadata = Metadata::default();
+    let tokens: Vec<&str> = prefix.split_whitespace().collect();
+
+    if let Some(position) = tokens.iter().position(|token| *token == "kernel:") {
+        metadata.computer = position
+            .checked_sub(1)
+            .and_then(|i| tokens.get(i))
+            .map(|computer| String::from(*computer));
+        metadata.time = tokens.first().and_then(|token| Time::from_iso8601(token));
+    } else if let Some(timestamp) = prefix
+        .trim_start()
+        .strip_prefix('[')
+        .and_then(|s| s.split_once(']'))
+        .map(|(timestamp, _)| timestamp)
+    {
+        metadata.time = parse_ctime(timestamp);
+    }
+
+    metadata
+}
+
+/// Rebuilds the Crash Logs printed in a Linux kernel log.
+///
+/// Each `event severity` block printed by the kernel is converted into a separate [CrashLog].
+/// Events that do not contain any Crash Log record are ignored.
+pub(crate) fn crashlogs_from_kernel_log(log: &str) -> Result<Vec<CrashLog>, Error> {
+    let mut events: Vec<Event> = Vec::new();
+
+    for line in log.lines() {
+        let Some((prefix, message)) = line.split_once(HW_ERR) else {
+            continue;
+        };
+        let message = message.trim();
+
+        if message.starts_with("event severity:") {
+            events.push(Event {
+                metadata: parse_prefix(prefix),
+                sections: Vec::new(),
+            });
+            continue;
+        }
+
+        let Some(event) = events.last_mut() else {
+            continue;
+        };
+
+        if message.starts_with("Error ") && message.contains(", type:") {
+            event.sections.push(Section::new());
+        } else if let Some(section) = event.sections.last_mut() {
+            section.parse_line(message);
+        }
+    }
+
+    let crashlogs: Vec<CrashLog> = events
+        .into_iter()
+        .filter_map(Event::into_crashlog)
+        .collect();
+
+    log::info!("Found {} Crash Logs in the kernel log", crashlogs.len());
+
+    if crashlogs.is_empty() {
+        return Err(Error::NoCrashLogFound);
+    }
+
+    Ok(crashlogs)
+}
@@ -0,0 +1,107 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::{Section, parse_hex_dump_line, parse_prefix};
+use crate::CrashLog;
+use uguid::{Guid, guid};
+
+#[test]
+fn hex_dump_line() {
+    assert_eq!(
+        parse_hex_dump_line("00000010: 8f87f311 4d9ec998  .......M"),
+        Some((0x10, vec![0x11, 0xf3, 0x87, 0x8f, 0x98, 0xc9, 0x9e, 0x4d]))
+    );
+    assert_eq!(
+        parse_hex_dump_line("00000020: 01 a3 07  ..."),
+        Some((0x20, vec![0x01, 0xa3, 0x07]))
+    );
+    assert_eq!(parse_hex_dump_line("Revision: 2"), None);
+}
+
+#[test]
+fn prefix() {
+    let metadata = parse_prefix("2025-01-31T12:34:56+0000 host kernel: {1}");
+    assert_eq!(metadata.computer.as_deref(), Some("host"));
+    let time = metadata.time.unwrap();
+    assert_eq!((time.year, time.month, time.day), (2025, 1, 31));
+    assert_eq!((time.hour, time.minute), (12, 34));
+
+    let metadata = parse_prefix("[Fri Jan 31 12:34:56 2025] ");
+    assert_eq!(metadata.computer, None);
+    assert_eq!(metadata.time.unwrap().to_string(), "2025-01-31-12-34");
+
+    let metadata = parse_prefix("[    1.234567] ");
+    assert!(metadata.computer.is_none() && metadata.time.is_none());
+}
+
+#[test]
+fn crashlogs_from_kernel_log() {
+    let log = std::fs::read_to_string("tests/samples/kernel_log.txt").unwrap();
+    let crashlogs = CrashLog::from_kernel_log(&log).unwrap();
+    assert_eq!(crashlogs.len(), 3);
+
+    let crashlog = &crashlogs[0];
+    assert_eq!(crashlog.regions.len(), 1);
+    assert_eq!(
+        crashlog.regions[0].records[0].header.record_type().unwrap(),
+        "MCA"
+    );
+    assert_eq!(crashlog.regions[0].records[0].data.len(), 832);
+    assert!(crashlog.metadata.time.is_none());
+
+    let crashlog = &crashlogs[1];
+    assert_eq!(crashlog.regions.len(), 1);
+    assert_eq!(
+        crashlog.regions[0].records[0].header.record_type().unwrap(),
+        "CRASHLOG_AGENT"
+    );
+
+    let crashlog = &crashlogs[2];
+    assert_eq!(crashlog.regions.len(), 1);
+    assert_eq!(
+        crashlog.regions[0].records[0].header.record_type().unwrap(),
+        "CRASHLOG_AGENT"
+    );
+    assert_eq!(
+        crashlog.metadata.to_string(),
+        "lab-host-07-2026-10-17-08-42"
+    );
+}
+
+#[test]
+fn no_crashlog_in_kernel_log() {
+    let log = "[    0.000000] Linux version 6.8.0\n[    1.000000] [Hardware Error]: event severity: corrected";
+    assert!(CrashLog::from_kernel_log(log).is_err());
+}
+
+#[test]
+fn record_identifier() {
+    let mut section = Section::new();
+    for line in [
+        "section_type: Firmware Error Record Reference",
+        "Firmware Error Record Type: SOC Firmware Error Record Type1 (Legacy CrashLog Support)",
+        "Revision: 0",
+        "Record Identifier: 0123456789abcdef",
+    ] {
+        section.parse_line(line);
+    }
+    assert_eq!({ section.header.error_type }, 1);
+    assert_eq!({ section.header.record_identifier }, 0x0123456789abcdef);
+    assert_eq!({ section.header.guid }, Guid::ZERO);
+
+    let mut section = Section::new();
+    for line in [
+        "section_type: Firmware Error Record Reference",
+        "Firmware Error Record Type: SOC Firmware Error Record Type2",
+        "Revision: 2",
+        "Record Identifier: 8f87f311-c998-4d9e-a0c4-6065518c4f6d",
+    ] {
+        section.parse_line(line);
+    }
+    assert_eq!({ section.header.error_type }, 2);
+    assert_eq!({ section.header.record_identifier }, 0);
+    assert_eq!(
+        { section.header.guid },
+        guid!("8f87f311-c998-4d9e-a0c4-6065518c4f6d")
+    );
+}
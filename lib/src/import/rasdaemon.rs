@@ -0,0 +1,137 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+//! Reader for the error events recorded by rasdaemon in its SQLite database.
+
+#[cfg(test)]
+mod tests;
+
+use crate::CrashLog;
+use crate::cper::CperSection;
+use crate::error::Error;
+use crate::metadata::{Metadata, Time};
+use crate::region::Region;
+use rusqlite::{Connection, OpenFlags, types::Value};
+use std::path::Path;
+use uguid::Guid;
+
+/// Location of the database used by rasdaemon on the local host.
+const DEFAULT_DB_PATH: &str = "/var/lib/rasdaemon/ras-mc_event.db";
+const HOSTNAME_PATH: &str = "/proc/sys/kernel/hostname";
+
+/// Row of the `non_standard_event` table
+struct NonStandardEvent {
+    timestamp: String,
+    section_type: Option<Guid>,
+    error: Vec<u8>,
+}
+
+/// Decodes the section type GUID which is stored either as raw bytes or as a string.
+fn parse_section_type(value: Value) -> Option<Guid> {
+    match value {
+        Value::Blob(blob) if blob.len() == 16 => Some(Guid::from_bytes(blob.try_into().ok()?)),
+        Value::Blob(blob) => {
+            Guid::try_parse(std::str::from_utf8(&blob).ok()?.trim_end_matches('\0')).ok()
+        }
+        Value::Text(text) => Guid::try_parse(&text).ok(),
+        _ => None,
+    }
+}
+
+fn has_table(connection: &Connection, table: &str) -> Result<bool, Error> {
+    let mut statement =
+        connection.prepare("SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?1")?;
+    Ok(statement.exists([table])?)
+}
+
+fn read_non_standard_events(connection: &Connection) -> Result<Vec<NonStandardEvent>, Error> {
+    if !has_table(connection, "non_standard_event")? {
+        log::info!("The rasdaemon database does not contain any non-standard event");
+        return Ok(Vec::new());
+    }
+
+    let mut statement = connection
+        .prepare("SELECT timestamp, sec_type, error FROM non_standard_event ORDER BY id")?;
+    let events = statement
+        .query_map([], |row| {
+            Ok(NonStandardEvent {
+                timestamp: row.get::<_, Option<String>>(0)?.unwrap_or_default(),
+                section_type: parse_section_type(row.get(1)?),
+                error: row.get::<_, Option<Vec<u8>>>(2)?.unwrap_or_default(),
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(events)
+}
+
+fn crashlog_from_events(events: &[NonStandardEvent], metadata: &Metadata) -> Option<CrashLog> {
+    let regions: Vec<Region> = events
+        .iter()
+        .filter_map(|event| CperSection::from_slice(event.section_type?, &event.error))
+        .filter_map(|section| Region::from_cper_section(&section))
+        .collect();
+
+    if regions.is_empty() {
+        return None;
+    }
+
+    let mut crashlog = CrashLog::from_regions(regions)
+        .inspect_err(|err| log::warn!("Cannot rebuild Crash Log from rasdaemon event: {err}"))
+        .ok()?;
+
+    crashlog.metadata = Metadata {
+        computer: metadata.computer.clone(),
+        time: Time::from_iso8601(&events[0].timestamp),
+        platform: metadata.platform.clone(),
+        ..Metadata::default()
+    };
+    Some(crashlog)
+}
+
+fn host_metadata() -> Metadata {
+    Metadata {
+        computer: std::fs::read_to_string(HOSTNAME_PATH)
+            .inspect_err(|err| log::warn!("Cannot read the host name: {err}"))
+            .ok()
+            .map(|hostname| hostname.trim().into()),
+        #[cfg(all(target_os = "linux", feature = "extraction"))]
+        platform: crate::extract::sysfs::read_platform_from_sysfs()
+            .inspect_err(|err| log::warn!("Cannot read the SMBIOS tables: {err}"))
+            .ok(),
+        ..Metadata::default()
+    }
+}
+
+/// Reads the Crash Logs stored in a rasdaemon database.
+///
+/// The firmware error records stored in the `non_standard_event` table are grouped by timestamp
+/// as the sections of a single error record are logged at the same time.
+pub(crate) fn crashlogs_from_rasdaemon_db(path: Option<&Path>) -> Result<Vec<CrashLog>, Error> {
+    let metadata = if path.is_none() {
+        host_metadata()
+    } else {
+        Metadata::default()
+    };
+
+    let path = path.unwrap_or(Path::new(DEFAULT_DB_PATH));
+    let connection = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    let events = read_non_standard_events(&connection)?;
+
+    let crashlogs: Vec<CrashLog> = events
+        .chunk_by(|a, b| a.timestamp == b.timestamp)
+        .filter_map(|events| crashlog_from_events(events, &metadata))
+        .collect();
+
+    log::info!(
+        "Extracted {} Crash Logs from {} rasdaemon events",
+        crashlogs.len(),
+        events.len()
+    );
+
+    if crashlogs.is_empty() {
+        return Err(Error::NoCrashLogFound);
+    }
+
+    Ok(crashlogs)
+}
@@ -0,0 +1,120 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::parse_section_type;
+use crate::CrashLog;
+use crate::cper::FW_ERROR_RECORD_GUID;
+use crate::cper::fer::{FirmwareErrorRecord, FirmwareErrorRecordHeader, RECORD_ID_CRASHLOG};
+use rusqlite::{Connection, types::Value};
+
+fn fer(sample: &str) -> Vec<u8> {
+    FirmwareErrorRecord {
+        header: FirmwareErrorRecordHeader {
+            error_type: 2,
+            revision: 2,
+            guid: RECORD_ID_CRASHLOG,
+            ..FirmwareErrorRecordHeader::default()
+        },
+        payload: std::fs::read(sample).unwrap(),
+    }
+    .to_bytes()
+}
+
+fn create_db(path: &std::path::Path) {
+    let _ = std::fs::remove_file(path);
+    let connection = Connection::open(path).unwrap();
+    connection
+        .execute(
+            "CREATE TABLE non_standard_event (id INTEGER PRIMARY KEY, timestamp TEXT, \
+             sec_type BLOB, fru_id BLOB, fru_text TEXT, severity TEXT, error BLOB)",
+            (),
+        )
+        .unwrap();
+
+    let events: [(&str, Value, Vec<u8>); 4] = [
+        (
+            "2026-10-17 08:42:12 +0000",
+            Value::Blob(FW_ERROR_RECORD_GUID.to_bytes().to_vec()),
+            fer("tests/samples/dummy_mca_rev1.crashlog"),
+        ),
+        (
+            "2026-10-17 08:42:12 +0000",
+            Value::Text(FW_ERROR_RECORD_GUID.to_string()),
+            fer("tests/samples/dummy_crashlog_agent_rev1.crashlog"),
+        ),
+        (
+            "2026-10-17 09:00:00 +0000",
+            Value::Text("d995e954-bbc1-430f-ad91-b44dcb3c6f35".into()),
+            vec![0; 16],
+        ),
+        (
+            "2026-10-17 10:15:30 +0000",
+            Value::Text(FW_ERROR_RECORD_GUID.to_string()),
+            fer("tests/samples/dummy_crashlog_agent_rev1.crashlog"),
+        ),
+    ];
+
+    for (timestamp, sec_type, error) in events {
+        connection
+            .execute(
+                "INSERT INTO non_standard_event (timestamp, sec_type, severity, error) \
+                 VALUES (?1, ?2, 'Fatal', ?3)",
+                (timestamp, sec_type, error),
+            )
+            .unwrap();
+    }
+}
+
+#[test]
+fn section_type() {
+    let guid = FW_ERROR_RECORD_GUID;
+    assert_eq!(
+        parse_section_type(Value::Blob(guid.to_bytes().to_vec())),
+        Some(guid)
+    );
+    assert_eq!(
+        parse_section_type(Value::Blob(guid.to_string().into_bytes())),
+        Some(guid)
+    );
+    assert_eq!(
+        parse_section_type(Value::Text(guid.to_string())),
+        Some(guid)
+    );
+    assert_eq!(parse_section_type(Value::Null), None);
+}
+
+#[test]
+fn crashlogs_from_rasdaemon_db() {
+    let path = std::env::temp_dir().join("iclg_rasdaemon_test.db");
+    create_db(&path);
+
+    let crashlogs = CrashLog::from_rasdaemon(Some(&path)).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(crashlogs.len(), 2);
+
+    let crashlog = &crashlogs[0];
+    assert_eq!(crashlog.regions.len(), 2);
+    assert_eq!(
+        crashlog.regions[0].records[0].header.record_type().unwrap(),
+        "MCA"
+    );
+    assert_eq!(
+        crashlog.regions[1].records[0].header.record_type().unwrap(),
+        "CRASHLOG_AGENT"
+    );
+    assert_eq!(crashlog.metadata.to_string(), "2026-10-17-08-42");
+
+    let crashlog = &crashlogs[1];
+    assert_eq!(crashlog.regions.len(), 1);
+    assert_eq!(crashlog.metadata.to_string(), "2026-10-17-10-15");
+}
+
+#[test]
+fn empty_rasdaemon_db() {
+    let path = std::env::temp_dir().join("iclg_rasdaemon_empty_test.db");
+    let _ = std::fs::remove_file(&path);
+    Connection::open(&path).unwrap();
+
+    assert!(CrashLog::from_rasdaemon(Some(&path)).is_err());
+    std::fs::remove_file(&path).unwrap();
+}
@@ -76,10 +76,10 @@
 //! let crashlog = CrashLog::from_slice(&data).unwrap();
 //!
 //! // Use product-specific decode definitions that are embedded in the crate's binary.
-//! let mut cm = CollateralManager::embedded_tree().unwrap();
+//! let cm = CollateralManager::embedded_tree().unwrap();
 //!
 //! // Decode the content of the Crash Log records into a register tree.
-//! let nodes = crashlog.decode(&mut cm);
+//! let nodes = crashlog.decode(&cm);
 //!
 //! // Get the status register of the fourth MCA bank from the register tree.
 //! let status = nodes.get_by_path("core0.thread.arch_state.mca.bank3.status").unwrap();
@@ -90,8 +90,25 @@
 //! assert_eq!(lip.kind, NodeType::Field { value: 0xfffff80577036530 });
 //! ```
 //!
+//! ### Borrowed Decoding
+//!
+//! [CrashLogRef] decodes the records in place without copying them, which reduces the memory
+//! footprint when processing large captures. [CrashLog] is built on top of it and copies the
+//! records once.
+//!
+//! ```
+//! use intel_crashlog::prelude::*;
+//!
+//! let data = std::fs::read("tests/samples/dummy.bert").unwrap();
+//! let crashlog = CrashLogRef::from_slice(&data).unwrap();
+//! assert_eq!(crashlog.regions.len(), 2);
+//! ```
+//!
 //! ## Default Features
 //!
+//! - `archive_collateral_tree`: provides support in the collateral manager for reading the
+//!   collateral tree from a `.zip`, `.tar` or `.tar.gz` archive (requires `std` and
+//!   `collateral_manager`).
 //! - `collateral_manager`: provides support for the project-specific decode definitions. See
 //!   [collateral] for more information.
 //! - `extraction`: provides functions to extract the Crash Log record from the platform.
@@ -101,6 +118,9 @@
 //!   for more information.
 //! - `fs_collateral_tree`: provides support in the collateral manager for reading collateral tree
 //!   from the file system at runtime (requires `std` and `collateral_manager`).
+//! - `http_collateral_tree`: provides support in the collateral manager for downloading the
+//!   collateral tree from an HTTP server, with an on-disk cache (requires `std` and
+//!   `collateral_manager`).
 //! - `serialize`: provides [serde::Serialize] implementation for the [node::Node] objects. This is
 //!   required to export the register tree to JSON.
 //! - `std`: when disabled, the crate won't depend on the Rust's standard library. Please note
@@ -109,10 +129,11 @@
 #[cfg(not(feature = "std"))]
 extern crate alloc;
 
-mod bert;
+pub mod bert;
 #[cfg(feature = "collateral_manager")]
 pub mod collateral;
-mod cper;
+pub mod container;
+pub mod cper;
 mod crashlog;
 mod error;
 #[cfg(feature = "extraction")]
@@ -120,12 +141,14 @@ mod extract;
 #[cfg(feature = "ffi")]
 pub mod ffi;
 pub mod header;
+mod import;
 pub mod metadata;
 pub mod node;
 pub mod prelude;
 pub mod record;
 pub mod region;
+mod smbios;
 mod utils;
 
-pub use crashlog::CrashLog;
+pub use crashlog::{CrashLog, CrashLogRef};
 pub use error::Error;
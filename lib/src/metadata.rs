@@ -3,19 +3,29 @@
 
 //! Information extracted alongside the Crash Log records.
 
+use crate::node::Node;
 #[cfg(not(feature = "std"))]
-use alloc::{fmt, string::String};
+use alloc::{
+    fmt, format,
+    string::{String, ToString},
+    vec::Vec,
+};
 #[cfg(feature = "std")]
 use std::fmt;
+use uguid::Guid;
 
 /// Crash Log Metadata
 #[derive(Default)]
 pub struct Metadata {
     pub computer: Option<String>,
     pub time: Option<Time>,
+    pub platform: Option<Platform>,
+    /// Header of the error record (CPER or BERT) that contained the Crash Log
+    pub source: Option<RecordSource>,
 }
 
 /// Crash Log Extraction Time
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Time {
     pub year: u16,
     pub month: u8,
@@ -24,6 +34,250 @@ pub struct Time {
     pub minute: u8,
 }
 
+/// Identity of the platform that generated the Crash Log, as reported by the SMBIOS tables
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Platform {
+    /// System manufacturer (SMBIOS Type 1)
+    pub system_vendor: Option<String>,
+    /// System product name (SMBIOS Type 1)
+    pub product: Option<String>,
+    /// System serial number (SMBIOS Type 1)
+    pub serial: Option<String>,
+    /// BIOS version (SMBIOS Type 0)
+    pub bios_version: Option<String>,
+    /// Socket designations of the processors (SMBIOS Type 4)
+    pub sockets: Vec<String>,
+}
+
+/// Header of the error record that contained the Crash Log.
+///
+/// The fields are extracted from the CPER record header (UEFI 2.10 - Section N.2.1) or from the
+/// Generic Error Status Block of the Boot Error Region (ACPI 6.5 - Section 18.3.2.7.1).
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RecordSource {
+    /// Severity of the error record
+    pub severity: u32,
+    /// Firmware timestamp of the error record (CPER only)
+    pub timestamp: Option<Time>,
+    /// Identifier of the platform that generated the error record (CPER only)
+    pub platform_id: Option<Guid>,
+    /// Identifier of the software that created the error record (CPER only)
+    pub creator_id: Option<Guid>,
+    /// Type of the notification used to report the error (CPER only)
+    pub notification_type: Option<Guid>,
+    /// Identifier of the error record (CPER only)
+    pub record_id: Option<u64>,
+    /// Status of the Generic Error Status Block (BERT only)
+    pub block_status: Option<u32>,
+}
+
+/// Header of the error section that contained a Crash Log region.
+///
+/// The fields are extracted from the CPER section descriptor (UEFI 2.10 - Section N.2.2) or from
+/// the Generic Error Data Entry of the Boot Error Region (ACPI 6.5 - Section 18.3.2.7.1).
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SectionSource {
+    /// Severity of the error section
+    pub severity: u32,
+    /// Identifier of the Field Replaceable Unit
+    pub fru_id: Option<Guid>,
+    /// Text describing the Field Replaceable Unit
+    pub fru_text: Option<String>,
+    /// Firmware timestamp of the error section (BERT only)
+    pub timestamp: Option<Time>,
+}
+
+/// Decodes the FRU text stored in an error section header.
+pub(crate) fn parse_fru_text(fru_text: &[u8]) -> Option<String> {
+    let end = fru_text
+        .iter()
+        .position(|c| *c == 0)
+        .unwrap_or(fru_text.len());
+    let text = String::from_utf8_lossy(&fru_text[..end]);
+    let text = text.trim();
+    if text.is_empty() {
+        None
+    } else {
+        Some(text.into())
+    }
+}
+
+/// Returns the register tree representation of a GUID (`EFI_GUID` fields).
+fn guid_node(name: &str, guid: Guid) -> Node {
+    let bytes = guid.to_bytes();
+    let mut node = Node::section(name);
+    node.add(Node::field(
+        "data1",
+        u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as u64,
+    ));
+    node.add(Node::field(
+        "data2",
+        u16::from_le_bytes([bytes[4], bytes[5]]) as u64,
+    ));
+    node.add(Node::field(
+        "data3",
+        u16::from_le_bytes([bytes[6], bytes[7]]) as u64,
+    ));
+    node.add(Node::field(
+        "data4",
+        u64::from_be_bytes([
+            bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15],
+        ]),
+    ));
+    node
+}
+
+/// Returns the register tree representation of a date and time.
+fn time_node(name: &str, time: &Time) -> Node {
+    let mut node = Node::section(name);
+    node.description = time.to_string();
+    node.add(Node::field("year", time.year as u64));
+    node.add(Node::field("month", time.month as u64));
+    node.add(Node::field("day", time.day as u64));
+    node.add(Node::field("hour", time.hour as u64));
+    node.add(Node::field("minute", time.minute as u64));
+    node
+}
+
+impl From<&Platform> for Node {
+    fn from(platform: &Platform) -> Self {
+        let mut node = Node::section("platform");
+        let strings = [
+            ("system_vendor", &platform.system_vendor),
+            ("product", &platform.product),
+            ("serial", &platform.serial),
+            ("bios_version", &platform.bios_version),
+        ];
+        for (name, value) in strings {
+            if let Some(value) = value {
+                node.add(Node::text(name, value));
+            }
+        }
+
+        if !platform.sockets.is_empty() {
+            let mut sockets = Node::section("sockets");
+            for (i, socket) in platform.sockets.iter().enumerate() {
+                sockets.add(Node::text(&format!("socket{i}"), socket));
+            }
+            node.add(sockets);
+        }
+        node
+    }
+}
+
+impl From<&RecordSource> for Node {
+    fn from(source: &RecordSource) -> Self {
+        let mut node = Node::section("record");
+        node.add(Node::field("severity", source.severity as u64));
+        if let Some(timestamp) = source.timestamp.as_ref() {
+            node.add(time_node("timestamp", timestamp));
+        }
+        if let Some(platform_id) = source.platform_id {
+            node.add(guid_node("platform_id", platform_id));
+        }
+        if let Some(creator_id) = source.creator_id {
+            node.add(guid_node("creator_id", creator_id));
+        }
+        if let Some(notification_type) = source.notification_type {
+            node.add(guid_node("notification_type", notification_type));
+        }
+        if let Some(record_id) = source.record_id {
+            node.add(Node::field("record_id", record_id));
+        }
+        if let Some(block_status) = source.block_status {
+            node.add(Node::field("block_status", block_status as u64));
+        }
+        node
+    }
+}
+
+impl From<&SectionSource> for Node {
+    fn from(source: &SectionSource) -> Self {
+        let mut node = Node::section("section");
+        node.description = source.fru_text.clone().unwrap_or_default();
+        node.add(Node::field("severity", source.severity as u64));
+        if let Some(fru_id) = source.fru_id {
+            node.add(guid_node("fru_id", fru_id));
+        }
+        if let Some(timestamp) = source.timestamp.as_ref() {
+            node.add(time_node("timestamp", timestamp));
+        }
+        node
+    }
+}
+
+impl Time {
+    /// Parses the date and time stored at the beginning of an ISO 8601 timestamp
+    /// (`2025-01-31T12:34:56+0000`). A space is also accepted as date and time separator.
+    pub(crate) fn from_iso8601(s: &str) -> Option<Self> {
+        let b = s.as_bytes();
+        if b.len() < 16 || b[4] != b'-' || b[7] != b'-' || b[13] != b':' {
+            return None;
+        }
+
+        if b[10] != b'T' && b[10] != b' ' {
+            return None;
+        }
+
+        Some(Time {
+            year: s.get(0..4)?.parse().ok()?,
+            month: s.get(5..7)?.parse().ok()?,
+            day: s.get(8..10)?.parse().ok()?,
+            hour: s.get(11..13)?.parse().ok()?,
+            minute: s.get(14..16)?.parse().ok()?,
+        })
+    }
+
+    /// Decodes an error record timestamp (UEFI 2.10 - Section N.2.1.1).
+    ///
+    /// The fields are encoded in BCD. Some firmware (e.g. WHEA) store them in binary instead,
+    /// which is assumed when one of the bytes is not a valid BCD value.
+    pub(crate) fn from_cper_timestamp(timestamp: u64) -> Option<Self> {
+        let bytes = timestamp.to_le_bytes();
+        // Byte 3 stores the flags and does not encode any field.
+        let fields = [0, 1, 2, 4, 5, 6, 7].map(|i| bytes[i]);
+        let decode: fn(u8) -> u8 = if fields.iter().all(|b| b >> 4 < 10 && b & 0xf < 10) {
+            |b| (b >> 4) * 10 + (b & 0xf)
+        } else {
+            |b| b
+        };
+
+        let time = Time {
+            year: decode(bytes[7]) as u16 * 100 + decode(bytes[6]) as u16,
+            month: decode(bytes[5]),
+            day: decode(bytes[4]),
+            hour: decode(bytes[2]),
+            minute: decode(bytes[1]),
+        };
+
+        if !(1..=12).contains(&time.month)
+            || !(1..=31).contains(&time.day)
+            || time.hour > 23
+            || time.minute > 59
+        {
+            log::warn!("Invalid error record timestamp: {timestamp:#x}");
+            return None;
+        }
+
+        Some(time)
+    }
+
+    /// Encodes the time as an error record timestamp (UEFI 2.10 - Section N.2.1.1).
+    pub(crate) fn to_cper_timestamp(&self) -> u64 {
+        let bcd = |value: u16| (((value / 10) << 4) | (value % 10)) as u8;
+        u64::from_le_bytes([
+            0,
+            bcd(self.minute as u16),
+            bcd(self.hour as u16),
+            0,
+            bcd(self.day as u16),
+            bcd(self.month as u16),
+            bcd(self.year % 100),
+            bcd(self.year / 100),
+        ])
+    }
+}
+
 impl fmt::Display for Metadata {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match (self.computer.as_ref(), self.time.as_ref()) {
@@ -35,6 +289,21 @@ impl fmt::Display for Metadata {
     }
 }
 
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let unknown = "unknown";
+        write!(
+            f,
+            "{} {} (serial: {}, BIOS: {}, sockets: {})",
+            self.system_vendor.as_deref().unwrap_or(unknown),
+            self.product.as_deref().unwrap_or(unknown),
+            self.serial.as_deref().unwrap_or(unknown),
+            self.bios_version.as_deref().unwrap_or(unknown),
+            self.sockets.len()
+        )
+    }
+}
+
 impl fmt::Display for Time {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
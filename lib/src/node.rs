@@ -3,6 +3,10 @@
 
 //! A tree-like data structure containing the decoded Crash Log registers.
 
+mod filter;
+
+pub use filter::PathFilter;
+
 #[cfg(feature = "std")]
 use std::collections::{BTreeMap, btree_map};
 
@@ -76,6 +80,26 @@ impl Node {
         }
     }
 
+    /// Returns a new section node that carries a `text` in its description.
+    ///
+    /// Such a node is serialized as its text as long as it has no children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let node = Node::text("foo", "bar");
+    /// assert_eq!(node.kind, NodeType::Section);
+    /// assert_eq!(node.description, "bar");
+    /// ```
+    pub fn text(name: &str, text: &str) -> Node {
+        Node {
+            description: text.into(),
+            ..Node::section(name)
+        }
+    }
+
     /// Returns a new record node.
     ///
     /// # Examples
@@ -248,6 +272,48 @@ impl Node {
             iter: self.children.values(),
         }
     }
+
+    /// Removes the child called `name` from the node and returns it.
+    #[cfg(all(feature = "collateral_manager", feature = "serialize", feature = "std"))]
+    pub(crate) fn remove(&mut self, name: &str) -> Option<Node> {
+        self.children.remove(name)
+    }
+
+    /// Consumes the node and returns its children, sorted alphabetically.
+    #[cfg(all(feature = "collateral_manager", feature = "serialize", feature = "std"))]
+    pub(crate) fn into_children(self) -> impl Iterator<Item = Node> {
+        self.children.into_values()
+    }
+
+    /// Removes the descendants of the node that are not selected by the `filter`.
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    /// use intel_crashlog::node::PathFilter;
+    ///
+    /// let mut root = Node::root();
+    /// root.create_hierarchy("foo.bar.baz");
+    /// root.create_hierarchy("foo.qux");
+    /// root.create_hierarchy("quux");
+    ///
+    /// root.retain(&PathFilter::new(["f*.bar"]));
+    /// assert!(root.get_by_path("foo.bar.baz").is_some());
+    /// assert!(root.get_by_path("foo.qux").is_none());
+    /// assert!(root.get("quux").is_none());
+    /// ```
+    pub fn retain(&mut self, filter: &PathFilter) {
+        self.children.retain(|name, child| {
+            let filter = filter.child(name, false);
+            if filter.selects_all() {
+                return true;
+            }
+            if filter.is_empty() {
+                return false;
+            }
+            child.retain(&filter);
+            !child.children.is_empty()
+        });
+    }
 }
 
 #[cfg(feature = "serialize")]
@@ -269,6 +335,9 @@ impl Serialize for Node {
                     map.end()
                 }
             }
+            NodeType::Section if self.children.is_empty() && !self.description.is_empty() => {
+                serializer.serialize_str(&self.description)
+            }
             NodeType::Root => {
                 let mut map = serializer.serialize_map(Some(1))?;
                 map.serialize_entry("crashlog_data", &self.children)?;
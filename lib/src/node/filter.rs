@@ -0,0 +1,124 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec, vec::Vec};
+
+/// Selection of paths in the register tree.
+///
+/// Each path is a dot-separated list of node names (example: `core0.thread.arch_state.lip`).
+/// A path selects the node it points to, all its descendants, and all its ancestors. The node
+/// names can contain `*` wildcards that match any sequence of characters (example:
+/// `core*.thread.arch_state.lip`).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PathFilter {
+    patterns: Vec<Vec<String>>,
+}
+
+impl PathFilter {
+    /// Creates a filter that selects the given `paths`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::node::PathFilter;
+    ///
+    /// let filter = PathFilter::new(["core*.thread.arch_state.lip", "mca"]);
+    /// assert!(!filter.is_empty());
+    /// ```
+    pub fn new<I, S>(paths: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            patterns: paths
+                .into_iter()
+                .map(|path| path.as_ref().split('.').map(String::from).collect())
+                .collect(),
+        }
+    }
+
+    /// Creates a filter from paths that are already split into node names.
+    #[cfg(all(feature = "collateral_manager", feature = "serialize", feature = "std"))]
+    pub(crate) fn from_segments(patterns: Vec<Vec<String>>) -> Self {
+        Self { patterns }
+    }
+
+    /// Returns a filter that selects the whole register tree.
+    pub(crate) fn all() -> Self {
+        Self {
+            patterns: vec![Vec::new()],
+        }
+    }
+
+    /// Returns true if the filter does not select any node.
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// Returns true if the filter selects all the nodes.
+    pub(crate) fn selects_all(&self) -> bool {
+        self.patterns.iter().any(|pattern| pattern.is_empty())
+    }
+
+    /// Returns the filter to apply to the children of the node called `name`.
+    ///
+    /// If `instance` is set, the node is also matched as if it was suffixed by an instance
+    /// number, as it happens when several nodes with the same name are merged.
+    pub(crate) fn child(&self, name: &str, instance: bool) -> Self {
+        Self {
+            patterns: self
+                .patterns
+                .iter()
+                .filter_map(|pattern| match pattern.split_first() {
+                    None => Some(Vec::new()),
+                    Some((head, tail)) if glob(head.as_bytes(), name.as_bytes(), instance) => {
+                        Some(tail.to_vec())
+                    }
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the filter to apply to the descendants of the node located at `path`, matching
+    /// the node names with any instance number.
+    pub(crate) fn strip_prefix<I, S>(&self, path: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        path.into_iter().fold(self.clone(), |filter, name| {
+            filter.child(name.as_ref(), true)
+        })
+    }
+
+    /// Returns true if the node located at `path` may be selected once merged in the register
+    /// tree, regardless of its instance number.
+    pub(crate) fn matches<S: AsRef<str>>(&self, path: &[S]) -> bool {
+        self.patterns.iter().any(|pattern| {
+            pattern
+                .iter()
+                .zip(path)
+                .all(|(head, name)| glob(head.as_bytes(), name.as_ref().as_bytes(), true))
+        })
+    }
+}
+
+/// Matches a node `name` against a `pattern` that can contain `*` wildcards. If `instance` is
+/// set, the name can be followed by an instance number.
+fn glob(pattern: &[u8], name: &[u8], instance: bool) -> bool {
+    match (pattern.split_first(), name.split_first()) {
+        (None, None) => true,
+        (Some((b'*', tail)), _) => {
+            glob(tail, name, instance)
+                || name
+                    .split_first()
+                    .is_some_and(|(_, name)| glob(pattern, name, instance))
+        }
+        (Some((p, pattern)), Some((n, name))) => p == n && glob(pattern, name, instance),
+        (Some(_), None) => instance && pattern.iter().all(|c| c.is_ascii_digit() || *c == b'*'),
+        (None, Some(_)) => false,
+    }
+}
@@ -5,9 +5,10 @@
 
 #[cfg(feature = "collateral_manager")]
 pub use crate::collateral::{CollateralManager, CollateralTree};
-pub use crate::crashlog::CrashLog;
+pub use crate::container::ContainerType;
+pub use crate::crashlog::{CrashLog, CrashLogRef};
 pub use crate::error::Error;
 pub use crate::header::Header;
 pub use crate::node::{Node, NodeType};
-pub use crate::record::Record;
-pub use crate::region::Region;
+pub use crate::record::{Record, RecordRef};
+pub use crate::region::{Region, RegionRef};
@@ -5,22 +5,30 @@
 
 mod core;
 mod decode;
+mod layout;
 
 use crate::header::Header;
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
+pub use layout::{Layout, LayoutField};
+
 /// A single Crash Log record
+///
+/// The raw content of the record is owned by default. See [RecordRef] for the borrowed variant.
 #[derive(Default)]
-pub struct Record {
+pub struct Record<D = Vec<u8>> {
     /// Header of the record
     pub header: Header,
     /// Raw content of the record
-    pub data: Vec<u8>,
+    pub data: D,
     /// Additional information provided to the record
     pub context: Context,
 }
 
+/// A Crash Log record whose content points to the binary it has been decoded from.
+pub type RecordRef<'a> = Record<&'a [u8]>;
+
 /// Additional data provided to a Crash Log record
 #[derive(Clone, Default)]
 pub struct Context {
@@ -30,16 +38,17 @@ pub struct Context {
     pub socket_id: Option<u8>,
 }
 
-impl Record {
+impl<D: AsRef<[u8]>> Record<D> {
     pub fn payload(&self) -> &[u8] {
+        let data = self.data.as_ref();
         let begin = self.header.header_size();
         let end = if self.header.version.cldic {
             // Checksum is present at the end of the record
-            self.data.len() - 4
+            data.len() - 4
         } else {
-            self.data.len()
+            data.len()
         };
-        &self.data[begin..end]
+        &data[begin..end]
     }
 
     pub fn checksum(&self) -> Option<bool> {
@@ -49,6 +58,7 @@ impl Record {
 
         let checksum = self
             .data
+            .as_ref()
             .chunks(4)
             .map(|dword_slice| u32::from_le_bytes(dword_slice.try_into().unwrap_or([0; 4])))
             .fold(0, |acc: u32, dword| acc.wrapping_add(dword));
@@ -56,3 +66,13 @@ impl Record {
         Some(checksum == 0)
     }
 }
+
+impl From<RecordRef<'_>> for Record {
+    fn from(record: RecordRef<'_>) -> Self {
+        Record {
+            header: record.header,
+            data: Vec::from(record.data),
+            context: record.context,
+        }
+    }
+}
@@ -1,40 +1,49 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
-use super::Record;
+use super::{Layout, Record};
 use crate::Error;
 #[cfg(feature = "collateral_manager")]
 use crate::collateral::{CollateralManager, CollateralTree};
-use crate::node::{Node, NodeType};
+use crate::node::{Node, PathFilter};
 #[cfg(not(feature = "std"))]
 use alloc::format;
 
-impl Record {
+impl<D: AsRef<[u8]>> Record<D> {
+    /// Returns the ID of the core that generated the record using the `hdr.whoami.core_id` field
+    /// of the record.
+    #[cfg(feature = "collateral_manager")]
+    fn core_id(&self, layout: &Layout) -> Option<u64> {
+        layout
+            .fields
+            .iter()
+            .find(|field| field.path.iter().skip(1).eq(["hdr", "whoami", "core_id"]))
+            .and_then(|field| self.read_field(field.offset, field.size))
+    }
+
     #[cfg(feature = "collateral_manager")]
     pub(super) fn decode_as_core_record<T: CollateralTree>(
         &self,
-        cm: &mut CollateralManager<T>,
+        cm: &CollateralManager<T>,
+        filter: &PathFilter,
     ) -> Result<Node, Error> {
         let mut section = Node::section("core");
 
         for decode_def in ["layout_thread.csv", "layout_core.csv"] {
-            if let Ok(thread) = self.decode_with_decode_def(cm, decode_def, 0) {
-                let core_id = thread
-                    .children()
-                    .next()
-                    .and_then(|child| child.get_by_path("hdr.whoami.core_id"))
-                    .map(|core_id| &core_id.kind);
-                if let Some(NodeType::Field { value }) = core_id {
-                    section.name = format!("core{value}");
+            if let Ok(layout) = self.get_layout(cm, decode_def) {
+                if let Some(core_id) = self.core_id(&layout) {
+                    section.name = format!("core{core_id}");
                 }
-                section.merge(thread);
+                let filter = filter.child(&section.name, true);
+                section.merge(self.decode_fields(&layout, 0, &filter));
             }
         }
 
         if let Some(offset) = self.header.extended_record_offset() {
+            let filter = filter.child(&section.name, true);
             for decode_def in ["layout_sq.csv", "layout_module.csv"] {
-                if let Ok(node) = self.decode_with_decode_def(cm, decode_def, offset) {
-                    section.merge(node);
+                if let Ok(layout) = self.get_layout(cm, decode_def) {
+                    section.merge(self.decode_fields(&layout, offset, &filter));
                     break;
                 }
             }
@@ -1,31 +1,21 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
-use super::Record;
+use super::{Layout, Record};
 #[cfg(feature = "collateral_manager")]
 use crate::collateral::{CollateralManager, CollateralTree};
 use crate::error::Error;
 use crate::header::record_types;
-use crate::node::Node;
-use crate::node::NodeType;
+use crate::node::{Node, NodeType, PathFilter};
+#[cfg(all(feature = "collateral_manager", not(feature = "std")))]
+use alloc::sync::Arc;
 #[cfg(not(feature = "std"))]
-use alloc::{borrow::ToOwned, format, str, string::String, vec::Vec};
-use log::debug;
-#[cfg(feature = "std")]
-use std::str;
-
-const DELIMITER: char = ';';
-
-#[derive(Default, Debug)]
-struct DecodeDefinitionEntry {
-    pub name: String,
-    pub offset: usize,
-    pub size: usize,
-    pub description: String,
-}
+use alloc::{format, string::String};
+#[cfg(all(feature = "collateral_manager", feature = "std"))]
+use std::sync::Arc;
 
-impl Record {
-    fn read_field(&self, offset: usize, size: usize) -> Option<u64> {
+impl<D: AsRef<[u8]>> Record<D> {
+    pub(super) fn read_field(&self, offset: usize, size: usize) -> Option<u64> {
         if size > 64 {
             // Large fields don't need to be decoded.
             return None;
@@ -37,13 +27,11 @@ impl Record {
         while bit < size {
             let chunk_size = 8;
             let chunk = (offset + bit) / chunk_size;
-            if chunk >= self.data.len() {
-                return None;
-            }
+            let byte = self.data.as_ref().get(chunk)?;
 
             let bit_offset = (offset + bit) % chunk_size;
             let mask = (1 << (size - bit).min(chunk_size)) - 1;
-            value |= ((self.data[chunk] as u64 >> bit_offset) & mask) << bit;
+            value |= ((*byte as u64 >> bit_offset) & mask) << bit;
             bit += chunk_size - bit_offset;
         }
 
@@ -81,68 +69,72 @@ impl Record {
     /// assert_eq!(field.kind, NodeType::Field { value: 0x42 });
     /// ```
     pub fn decode_with_csv(&self, layout: &[u8], offset: usize) -> Result<Node, Error> {
-        let mut root = Node::root();
-
-        let csv = str::from_utf8(layout)?;
-        let mut columns = Vec::new();
-        let mut current_path = Vec::new();
+        Ok(self.decode_with_layout(&Layout::from_csv(layout)?, offset))
+    }
 
-        for (i, line) in csv.lines().enumerate() {
-            if i == 0 {
-                columns = line.split(DELIMITER).collect();
-                debug!("CSV columns: {columns:?}");
-                continue;
-            }
+    /// Decodes a section of the [Record] located at the given `offset` into a [Node] tree using a
+    /// compiled decode definition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    /// use intel_crashlog::record::Layout;
+    ///
+    /// let record = Record {
+    ///     header: Header::default(),
+    ///     data: vec![0x42],
+    ///     ..Record::default()
+    /// };
+    ///
+    /// let csv = "name;offset;size;description;bitfield
+    /// foo.bar;0;8;;0";
+    ///
+    /// let layout = Layout::from_csv(csv.as_bytes()).unwrap();
+    /// let root = record.decode_with_layout(&layout, 0);
+    /// let field = root.get_by_path("foo.bar").unwrap();
+    /// assert_eq!(field.kind, NodeType::Field { value: 0x42 });
+    /// ```
+    pub fn decode_with_layout(&self, layout: &Layout, offset: usize) -> Node {
+        self.decode_fields(layout, offset, &PathFilter::all())
+    }
 
-            let mut entry = DecodeDefinitionEntry::default();
-
-            for (i, field) in line.split(DELIMITER).enumerate() {
-                if let Some(column) = columns.get(i) {
-                    match *column {
-                        "name" => entry.name = field.into(),
-                        "offset" => entry.offset = field.parse()?,
-                        "size" => entry.size = field.parse()?,
-                        "description" => entry.description = field.into(),
-                        _ => (),
-                    }
-                }
-            }
+    /// Decodes the fields of the `layout` that are selected by the `filter`.
+    ///
+    /// The record nodes are always created such that the instance numbers assigned while merging
+    /// the records remain the same as in a complete decode. For the same reason, the fields that
+    /// are not located under a record node are always decoded.
+    pub(super) fn decode_fields(
+        &self,
+        layout: &Layout,
+        offset: usize,
+        filter: &PathFilter,
+    ) -> Node {
+        let mut root = Node::root();
 
-            if entry.name.is_empty() {
-                continue;
+        for field in layout.fields.iter() {
+            if let (true, Some(top)) = (field.record, field.path.first())
+                && root.get(top).is_none()
+            {
+                root.add(Node::record(top));
             }
 
-            let mut segments = entry.name.split(".");
-            let Some(top) = segments.next() else {
+            let in_record = field
+                .path
+                .first()
+                .and_then(|top| root.get(top))
+                .is_some_and(|node| matches!(node.kind, NodeType::Record | NodeType::Field { .. }));
+            if in_record && !filter.matches(&field.path) {
                 continue;
-            };
-
-            if !top.is_empty() {
-                // Absolute path
-                current_path.clear();
-                current_path.push(top.to_owned());
-
-                if root.get(top).is_none() {
-                    // Top-level is assumed to be the record name
-                    root.add(Node::record(top));
-                }
-            }
-
-            for segment in segments {
-                if segment.is_empty() {
-                    let _ = current_path.pop();
-                } else {
-                    current_path.push(segment.to_owned());
-                }
             }
 
-            let node = root.create_hierarchy_from_iter(&current_path);
-            node.description = entry.description;
-            if let Some(value) = self.read_field(offset * 8 + entry.offset, entry.size) {
+            let node = root.create_hierarchy_from_iter(&field.path);
+            node.description = field.description.clone();
+            if let Some(value) = self.read_field(offset * 8 + field.offset, field.size) {
                 node.kind = NodeType::Field { value }
             }
         }
-        Ok(root)
+        root
     }
 
     /// Decodes the [Record] header into a [Node] tree.
@@ -183,7 +175,7 @@ impl Record {
     #[cfg(feature = "collateral_manager")]
     fn get_root_path_using_cm<T: CollateralTree>(
         &self,
-        cm: &mut CollateralManager<T>,
+        cm: &CollateralManager<T>,
     ) -> Option<String> {
         if let Some(custom_root) = self.header.get_root_path_using_cm(cm) {
             return Some(custom_root);
@@ -201,40 +193,128 @@ impl Record {
         None
     }
 
+    /// Returns the compiled decode definition called `decode_def` that applies to the [Record].
+    #[cfg(feature = "collateral_manager")]
+    pub(super) fn get_layout<T: CollateralTree>(
+        &self,
+        cm: &CollateralManager<T>,
+        decode_def: &str,
+    ) -> Result<Arc<Layout>, Error> {
+        for mut path in self.header.decode_definitions_paths(cm)? {
+            path.push(decode_def);
+            if let Ok(layout) = cm.get_layout_with_header(&self.header, path) {
+                return Ok(layout);
+            }
+        }
+
+        Err(Error::MissingDecodeDefinitions(self.header.version.clone()))
+    }
+
+    /// Checks whether the collateral tree provides decode definitions for the payload of the
+    /// [Record]. If not, only the header of the [Record] is decoded by [Record::decode].
+    #[cfg(feature = "collateral_manager")]
+    pub fn has_decode_definitions<T: CollateralTree>(&self, cm: &CollateralManager<T>) -> bool {
+        self.header
+            .version
+            .decode_definitions()
+            .iter()
+            .any(|decode_def| self.get_layout(cm, decode_def).is_ok())
+    }
+
     /// Decodes a section of the [Record] located at the given `offset` into a [Node] tree using
     /// an arbitrary decode definition stored in the collateral tree.
     #[cfg(feature = "collateral_manager")]
     pub fn decode_with_decode_def<T: CollateralTree>(
         &self,
-        cm: &mut CollateralManager<T>,
+        cm: &CollateralManager<T>,
         decode_def: &str,
         offset: usize,
     ) -> Result<Node, Error> {
-        let paths = self.header.decode_definitions_paths(cm)?;
+        let layout = self.get_layout(cm, decode_def)?;
+        Ok(self.decode_with_layout(&layout, offset))
+    }
 
-        let mut root = Node::root();
+    /// Decodes the whole [Record] into a [Node] tree using the decode definitions stored in the
+    /// collateral tree.
+    #[cfg(feature = "collateral_manager")]
+    pub fn decode<T: CollateralTree>(&self, cm: &CollateralManager<T>) -> Node {
+        self.decode_with_filter(cm, &PathFilter::all())
+    }
 
-        for mut path in paths {
-            path.push(decode_def);
-            let Ok(layout) = cm.get_item_with_header(&self.header, path) else {
-                continue;
-            };
-            root.merge(self.decode_with_csv(layout, offset)?);
-            return Ok(root);
-        }
+    /// Decodes the fields of the [Record] that are selected by the `filter` into a [Node] tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    /// use intel_crashlog::node::PathFilter;
+    ///
+    /// let data = std::fs::read("tests/samples/three_strike_timeout.crashlog").unwrap();
+    /// let crashlog = CrashLog::from_slice(&data).unwrap();
+    /// let cm = CollateralManager::embedded_tree().unwrap();
+    ///
+    /// let filter = PathFilter::new(["core*.thread.arch_state.lip"]);
+    /// let mut root = Node::root();
+    /// for record in crashlog.regions.iter().flat_map(|region| region.records.iter()) {
+    ///     root.merge(record.decode_filtered(&cm, &filter));
+    /// }
+    /// let lip = root.get_by_path("core0.thread.arch_state.lip").unwrap();
+    /// assert_eq!(lip.kind, NodeType::Field { value: 0xfffff80577036530 });
+    /// assert!(root.get_by_path("core0.thread.hdr").is_none());
+    /// ```
+    #[cfg(feature = "collateral_manager")]
+    pub fn decode_filtered<T: CollateralTree>(
+        &self,
+        cm: &CollateralManager<T>,
+        filter: &PathFilter,
+    ) -> Node {
+        let mut root = self.decode_with_filter(cm, filter);
+        root.retain(filter);
+        root
+    }
 
-        Err(Error::MissingDecodeDefinitions(self.header.version.clone()))
+    /// Decodes the fields of the [Record] that may be selected by the `filter` once merged with
+    /// the other records.
+    #[cfg(feature = "collateral_manager")]
+    pub(crate) fn decode_with_filter<T: CollateralTree>(
+        &self,
+        cm: &CollateralManager<T>,
+        filter: &PathFilter,
+    ) -> Node {
+        self.decode_selection(cm, filter, true)
+    }
+
+    /// Decodes the structure of the [Record] register tree down to the record nodes, which are
+    /// left empty.
+    #[cfg(all(feature = "collateral_manager", feature = "serialize", feature = "std"))]
+    pub(crate) fn decode_structure<T: CollateralTree>(&self, cm: &CollateralManager<T>) -> Node {
+        self.decode_selection(cm, &PathFilter::default(), false)
     }
 
-    /// Decodes the whole [Record] into a [Node] tree using the decode definitions stored in the
-    /// collateral tree.
     #[cfg(feature = "collateral_manager")]
-    pub fn decode<T: CollateralTree>(&self, cm: &mut CollateralManager<T>) -> Node {
+    fn decode_selection<T: CollateralTree>(
+        &self,
+        cm: &CollateralManager<T>,
+        filter: &PathFilter,
+        skip_empty: bool,
+    ) -> Node {
+        let custom_root = self.get_root_path_using_cm(cm);
+        let record_filter = match &custom_root {
+            Some(custom_root) => filter.strip_prefix(custom_root.split('.')),
+            None => filter.clone(),
+        };
+
+        let mut root = Node::root();
+        if skip_empty && record_filter.is_empty() {
+            return root;
+        }
+
         let record =
             if let record_types::PCORE | record_types::ECORE = self.header.version.record_type {
-                self.decode_as_core_record(cm)
+                self.decode_as_core_record(cm, &record_filter)
             } else {
-                self.decode_with_decode_def(cm, "layout.csv", 0)
+                self.get_layout(cm, "layout.csv")
+                    .map(|layout| self.decode_fields(&layout, 0, &record_filter))
             };
 
         let record_node = match record {
@@ -245,8 +325,7 @@ impl Record {
             }
         };
 
-        let mut root = Node::root();
-        let record_root = if let Some(custom_root) = self.get_root_path_using_cm(cm) {
+        let record_root = if let Some(custom_root) = custom_root {
             root.create_hierarchy(&custom_root)
         } else {
             &mut root
@@ -0,0 +1,50 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+mod csv;
+
+use crate::Error;
+#[cfg(not(feature = "std"))]
+use alloc::{str, vec::Vec};
+#[cfg(feature = "std")]
+use std::str;
+
+pub use csv::LayoutField;
+
+/// Compiled representation of a decode definition
+///
+/// Compiling the decode definition once allows it to be applied to many records without parsing
+/// its CSV content again. The relative paths of the decode definition are resolved during the
+/// compilation.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Layout {
+    /// Fields defined in the decode definition, in order of appearance
+    pub fields: Vec<LayoutField>,
+}
+
+impl Layout {
+    /// Compiles a CSV-encoded decode definition.
+    ///
+    /// See [`super::Record::decode_with_csv`] for the format of the decode definition. If a field
+    /// cannot be compiled, [`Error::InvalidDecodeDefinition`] reports the line where it is defined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::record::Layout;
+    ///
+    /// let csv = "name;offset;size;description;bitfield
+    /// foo.bar;0;8;;0
+    /// ..baz;8;8;;0";
+    ///
+    /// let layout = Layout::from_csv(csv.as_bytes()).unwrap();
+    /// assert_eq!(layout.fields[1].path, ["foo", "baz"]);
+    /// assert_eq!(layout.fields[1].offset, 8);
+    /// assert_eq!(layout.fields[1].line, 3);
+    /// ```
+    pub fn from_csv(csv: &[u8]) -> Result<Self, Error> {
+        let fields = csv::compile(str::from_utf8(csv)?)
+            .map_err(|err| Error::InvalidDecodeDefinition(err.line, err.error))?;
+        Ok(Self { fields })
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+// This file is also included by the build script to pre-compile the layouts of the embedded
+// collateral tree. It must not depend on the rest of the crate.
+
+#[cfg(not(feature = "std"))]
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use core::num::ParseIntError;
+
+const DELIMITER: char = ';';
+
+/// A single field of a compiled [`super::Layout`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LayoutField {
+    /// Absolute path to the field in the decode output
+    pub path: Vec<String>,
+    /// Indicates if the first element of the path is the name of a record
+    pub record: bool,
+    /// Offset of the field in the record in bits
+    pub offset: usize,
+    /// Size of the field in bits
+    pub size: usize,
+    /// Description of the field
+    pub description: String,
+    /// Line of the decode definition where the field is defined
+    pub line: usize,
+}
+
+/// Error reported when a line of a decode definition cannot be compiled
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompileError {
+    /// Line of the decode definition where the error has been found
+    pub line: usize,
+    /// Integer that cannot be parsed
+    pub error: ParseIntError,
+}
+
+/// Compiles a CSV-encoded decode definition into a list of fields.
+pub fn compile(csv: &str) -> Result<Vec<LayoutField>, CompileError> {
+    let mut fields = Vec::new();
+    let mut columns = Vec::new();
+    let mut current_path: Vec<String> = Vec::new();
+
+    for (i, line) in csv.lines().enumerate() {
+        if i == 0 {
+            columns = line.split(DELIMITER).collect();
+            continue;
+        }
+
+        let mut name = "";
+        let mut field = LayoutField {
+            line: i + 1,
+            ..LayoutField::default()
+        };
+
+        let parse = |value: &str| {
+            value
+                .parse()
+                .map_err(|error| CompileError { line: i + 1, error })
+        };
+
+        for (i, value) in line.split(DELIMITER).enumerate() {
+            if let Some(column) = columns.get(i) {
+                match *column {
+                    "name" => name = value,
+                    "offset" => field.offset = parse(value)?,
+                    "size" => field.size = parse(value)?,
+                    "description" => field.description = value.into(),
+                    _ => (),
+                }
+            }
+        }
+
+        if name.is_empty() {
+            continue;
+        }
+
+        let mut segments = name.split(".");
+        let Some(top) = segments.next() else {
+            continue;
+        };
+
+        if !top.is_empty() {
+            // Absolute path
+            current_path.clear();
+            current_path.push(top.to_owned());
+            // Top-level is assumed to be the record name
+            field.record = true;
+        }
+
+        for segment in segments {
+            if segment.is_empty() {
+                let _ = current_path.pop();
+            } else {
+                current_path.push(segment.to_owned());
+            }
+        }
+
+        field.path = current_path.clone();
+        fields.push(field);
+    }
+
+    Ok(fields)
+}
@@ -5,8 +5,9 @@
 
 use crate::cper::{CperSection, fer};
 use crate::error::Error;
-use crate::header::Header;
-use crate::record::Record;
+use crate::header::{Header, HeaderType, record_types};
+use crate::metadata::SectionSource;
+use crate::record::{Context, Record, RecordRef};
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
@@ -17,33 +18,30 @@ use alloc::vec::Vec;
 #[derive(Default)]
 pub struct Region {
     pub records: Vec<Record>,
+    /// Header of the error section that contained the region
+    pub source: Option<SectionSource>,
+    /// Index of the region whose Box record contains this region
+    pub parent: Option<usize>,
+    /// Bytes located after the last record (termination marker and unused space)
+    pub trailer: Vec<u8>,
 }
 
-impl Region {
-    pub(crate) fn from_cper_section(section: &CperSection) -> Option<Self> {
-        match section {
-            CperSection::FirmwareErrorRecord(fer) => {
-                let guid = fer.header.guid;
-                if guid == fer::RECORD_ID_CRASHLOG {
-                    Region::from_slice(&fer.payload).ok()
-                } else {
-                    log::info!("Ignoring unknown Firmware Error Record: {}", guid);
-                    None
-                }
-            }
-            _ => None,
-        }
-    }
-
-    pub(crate) fn set_socket_and_die_ids(&mut self, socket_id: u8, die_id: u8) {
-        for record in self.records.iter_mut() {
-            record.context.socket_id = Some(socket_id);
-            record.context.die_id = Some(die_id);
-        }
-    }
+/// A [Region] whose records point to the binary it has been decoded from.
+#[derive(Default)]
+pub struct RegionRef<'a> {
+    pub records: Vec<RecordRef<'a>>,
+    /// Header of the error section that contained the region
+    pub source: Option<SectionSource>,
+    /// Index of the region whose Box record contains this region
+    pub parent: Option<usize>,
+    /// Bytes located after the last record (termination marker and unused space)
+    pub trailer: &'a [u8],
+}
 
-    pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
-        let mut region = Region::default();
+impl<'a> RegionRef<'a> {
+    /// Decodes the Crash Log records stored in a byte slice without copying them.
+    pub fn from_slice(bytes: &'a [u8]) -> Result<Self, Error> {
+        let mut region = RegionRef::default();
         let mut cursor = 0;
 
         while cursor < bytes.len() {
@@ -85,23 +83,114 @@ impl Region {
                 )
             }
 
-            region.records.push(Record {
+            region.records.push(RecordRef {
                 header,
-                data: bytes[cursor..limit.min(bytes.len())].into(),
-                ..Default::default()
+                data: &bytes[cursor..limit.min(bytes.len())],
+                context: Context::default(),
             });
 
             cursor += record_size;
         }
 
+        region.trailer = bytes.get(cursor..).unwrap_or_default();
         Ok(region)
     }
 
+    /// Decodes the region stored in the payload of a Firmware Error Record.
+    pub(crate) fn from_fer_slice(s: &'a [u8]) -> Option<Self> {
+        RegionRef::from_slice(fer::crashlog_payload(s)?).ok()
+    }
+
+    /// Decodes the region stored in a Box record.
+    fn from_box_record(header: &Header, data: &'a [u8]) -> Option<Self> {
+        let Some(payload) = data.get(header.header_size()..) else {
+            log::error!("The Box record has an empty payload");
+            return None;
+        };
+
+        let mut region = RegionRef::from_slice(payload)
+            .inspect_err(|err| log::error!("Invalid region in Box record: {err}"))
+            .ok()?;
+        if let HeaderType::Type6 {
+            socket_id, die_id, ..
+        } = header.header_type
+        {
+            for record in region.records.iter_mut() {
+                record.context.socket_id = Some(socket_id);
+                record.context.die_id = Some(die_id);
+            }
+        }
+        Some(region)
+    }
+
+    /// Returns the regions stored in the Box records of this region, which is located at the
+    /// index `parent`.
+    pub(crate) fn box_regions(&self, parent: usize) -> Vec<Self> {
+        self.records
+            .iter()
+            .filter(|record| record.header.version.record_type == record_types::BOX)
+            .filter_map(|record| RegionRef::from_box_record(&record.header, record.data))
+            .map(|region| RegionRef {
+                source: self.source.clone(),
+                parent: Some(parent),
+                ..region
+            })
+            .collect()
+    }
+}
+
+impl From<RegionRef<'_>> for Region {
+    fn from(region: RegionRef<'_>) -> Self {
+        Region {
+            records: region.records.into_iter().map(Record::from).collect(),
+            source: region.source,
+            parent: region.parent,
+            trailer: Vec::from(region.trailer),
+        }
+    }
+}
+
+impl Region {
+    pub(crate) fn from_cper_section(section: &CperSection) -> Option<Self> {
+        match section {
+            CperSection::FirmwareErrorRecord(fer) => {
+                let guid = fer.header.guid;
+                if guid == fer::RECORD_ID_CRASHLOG {
+                    Region::from_slice(&fer.payload).ok()
+                } else {
+                    log::info!("Ignoring unknown Firmware Error Record: {}", guid);
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        RegionRef::from_slice(bytes).map(Region::from)
+    }
+
+    /// Returns the regions stored in the Box records of this region, which is located at the
+    /// index `parent`.
+    pub(crate) fn box_regions(&self, parent: usize) -> Vec<Self> {
+        self.records
+            .iter()
+            .filter(|record| record.header.version.record_type == record_types::BOX)
+            .filter_map(|record| RegionRef::from_box_record(&record.header, &record.data))
+            .map(|region| Region {
+                source: self.source.clone(),
+                parent: Some(parent),
+                ..Region::from(region)
+            })
+            .collect()
+    }
+
     pub fn to_bytes(&self) -> Vec<u8> {
         let mut bytes = Vec::new();
         for record in self.records.iter() {
-            bytes.append(&mut record.data.clone());
+            bytes.extend_from_slice(&record.data);
         }
+        bytes.extend_from_slice(&self.trailer);
         bytes
     }
 }
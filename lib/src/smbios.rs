@@ -0,0 +1,213 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+//! System Management BIOS (DMTF DSP0134) structure table parser.
+
+#[cfg(test)]
+mod tests;
+
+use crate::metadata::Platform;
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+use uguid::Guid;
+
+/// Section type used by this library to embed the SMBIOS structure table alongside the Crash Log
+/// records.
+///
+/// This section type is not defined by the UEFI specification. It is only written when explicitly
+/// requested using [crate::CrashLog::export_with_platform].
+pub const SMBIOS_SECTION_GUID: Guid = uguid::guid!("c9a17562-07d6-4a7f-a1d1-88eb7899ef51");
+
+const BIOS_INFORMATION: u8 = 0;
+const SYSTEM_INFORMATION: u8 = 1;
+const PROCESSOR_INFORMATION: u8 = 4;
+const END_OF_TABLE: u8 = 127;
+
+/// SMBIOS structure (DSP0134 - Section 6.1.2)
+struct Structure<'a> {
+    kind: u8,
+    formatted: &'a [u8],
+    strings: Vec<&'a [u8]>,
+}
+
+impl<'a> Structure<'a> {
+    /// Decodes the structure stored at the beginning of the slice and returns it alongside its
+    /// total size in bytes.
+    fn from_slice(s: &'a [u8]) -> Option<(Self, usize)> {
+        let kind = *s.first()?;
+        let length = *s.get(1)? as usize;
+        if length < 4 {
+            return None;
+        }
+        let formatted = s.get(..length)?;
+
+        let mut strings = Vec::new();
+        let mut cursor = length;
+        loop {
+            let end = cursor + s.get(cursor..)?.iter().position(|b| *b == 0)?;
+            if end == cursor {
+                if strings.is_empty() {
+                    // Structures without strings are terminated by two null bytes.
+                    cursor += 1;
+                    if *s.get(cursor)? != 0 {
+                        return None;
+                    }
+                }
+                break;
+            }
+            strings.push(&s[cursor..end]);
+            cursor = end + 1;
+        }
+
+        Some((
+            Structure {
+                kind,
+                formatted,
+                strings,
+            },
+            cursor + 1,
+        ))
+    }
+
+    /// Returns the string referenced by the byte located at `offset` in the formatted area.
+    fn string(&self, offset: usize) -> Option<String> {
+        let index = *self.formatted.get(offset)? as usize;
+        let raw = self.strings.get(index.checked_sub(1)?)?;
+        let string = String::from_utf8_lossy(raw);
+        let string = string.trim();
+        if string.is_empty() {
+            None
+        } else {
+            Some(string.into())
+        }
+    }
+}
+
+/// Serializes a structure with the given formatted area `length`. `strings` maps the offsets of
+/// the string fields in the formatted area to their values.
+fn write_structure(
+    bytes: &mut Vec<u8>,
+    kind: u8,
+    length: u8,
+    handle: u16,
+    strings: &[(usize, Option<&str>)],
+) {
+    let mut formatted = Vec::from([kind, length]);
+    formatted.extend_from_slice(&handle.to_le_bytes());
+    formatted.resize(length as usize, 0);
+
+    let mut string_set = Vec::new();
+    let mut index = 0;
+    for (offset, string) in strings {
+        if let Some(string) = string {
+            index += 1;
+            formatted[*offset] = index;
+            string_set.extend_from_slice(string.as_bytes());
+            string_set.push(0);
+        }
+    }
+
+    if string_set.is_empty() {
+        string_set.push(0);
+    }
+    string_set.push(0);
+
+    bytes.append(&mut formatted);
+    bytes.append(&mut string_set);
+}
+
+/// Returns the physical address and the maximum size of the structure table described by an
+/// SMBIOS entry point structure (DSP0134 - Section 5.2).
+#[cfg(any(all(target_os = "uefi", feature = "extraction"), test))]
+pub(crate) fn table_location(entry_point: &[u8]) -> Option<(u64, usize)> {
+    if entry_point.starts_with(b"_SM3_") {
+        let size = u32::from_le_bytes(entry_point.get(0x0C..0x10)?.try_into().ok()?);
+        let address = u64::from_le_bytes(entry_point.get(0x10..0x18)?.try_into().ok()?);
+        Some((address, size as usize))
+    } else if entry_point.starts_with(b"_SM_") {
+        let size = u16::from_le_bytes(entry_point.get(0x16..0x18)?.try_into().ok()?);
+        let address = u32::from_le_bytes(entry_point.get(0x18..0x1C)?.try_into().ok()?);
+        Some((address as u64, size as usize))
+    } else {
+        None
+    }
+}
+
+impl Platform {
+    /// Extracts the platform identity from a raw SMBIOS structure table.
+    pub(crate) fn from_smbios_table(s: &[u8]) -> Option<Self> {
+        let mut platform = Platform::default();
+        let mut cursor = 0;
+        let mut found = false;
+
+        while let Some((structure, size)) = s.get(cursor..).and_then(Structure::from_slice) {
+            cursor += size;
+            found = true;
+
+            match structure.kind {
+                BIOS_INFORMATION => platform.bios_version = structure.string(5),
+                SYSTEM_INFORMATION => {
+                    platform.system_vendor = structure.string(4);
+                    platform.product = structure.string(5);
+                    platform.serial = structure.string(7);
+                }
+                PROCESSOR_INFORMATION => {
+                    if let Some(socket) = structure.string(4) {
+                        platform.sockets.push(socket)
+                    }
+                }
+                END_OF_TABLE => break,
+                _ => (),
+            }
+        }
+
+        if !found {
+            log::warn!("No valid structure found in the SMBIOS table");
+            return None;
+        }
+
+        Some(platform)
+    }
+
+    /// Serializes the platform identity into a minimal SMBIOS structure table.
+    pub(crate) fn to_smbios_table(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut handle = 0;
+
+        write_structure(
+            &mut bytes,
+            BIOS_INFORMATION,
+            0x12,
+            handle,
+            &[(5, self.bios_version.as_deref())],
+        );
+        handle += 1;
+
+        write_structure(
+            &mut bytes,
+            SYSTEM_INFORMATION,
+            0x08,
+            handle,
+            &[
+                (4, self.system_vendor.as_deref()),
+                (5, self.product.as_deref()),
+                (7, self.serial.as_deref()),
+            ],
+        );
+        handle += 1;
+
+        for socket in self.sockets.iter() {
+            write_structure(
+                &mut bytes,
+                PROCESSOR_INFORMATION,
+                0x1A,
+                handle,
+                &[(4, Some(socket))],
+            );
+            handle += 1;
+        }
+
+        write_structure(&mut bytes, END_OF_TABLE, 0x04, handle, &[]);
+        bytes
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::table_location;
+use crate::container::ContainerType;
+use crate::metadata::Platform;
+use crate::{CrashLog, CrashLogRef};
+
+fn platform() -> Platform {
+    Platform {
+        system_vendor: Some("Intel Corporation".into()),
+        product: Some("XYZ Reference Platform".into()),
+        serial: Some("0123456789".into()),
+        bios_version: Some("XYZ.0042".into()),
+        sockets: vec!["CPU0".into(), "CPU1".into()],
+    }
+}
+
+#[test]
+fn from_smbios_table() {
+    let table = [
+        // Type 0 - BIOS Information
+        0x00, 0x12, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, b'1', b'.', b'2', 0x00, 0x00, // Type 1 - System Information
+        0x01, 0x08, 0x01, 0x00, 0x01, 0x02, 0x00, 0x03, b'A', b'c', b'm', b'e', 0x00, b'F', b'o',
+        b'o', b' ', 0x00, b'4', b'2', 0x00, 0x00, // Type 4 - Processor Information
+        0x04, 0x05, 0x02, 0x00, 0x01, b'C', b'P', b'U', b'0', 0x00, 0x00,
+        // Type 4 - Processor Information
+        0x04, 0x05, 0x03, 0x00, 0x01, b'C', b'P', b'U', b'1', 0x00, 0x00,
+        // Type 4 - Processor Information without socket designation
+        0x04, 0x05, 0x04, 0x00, 0x00, 0x00, 0x00, // Type 127 - End-of-Table
+        0x7f, 0x04, 0x05, 0x00, 0x00, 0x00,
+    ];
+
+    let platform = Platform::from_smbios_table(&table).unwrap();
+    assert_eq!(platform.system_vendor.as_deref(), Some("Acme"));
+    assert_eq!(platform.product.as_deref(), Some("Foo"));
+    assert_eq!(platform.serial.as_deref(), Some("42"));
+    assert_eq!(platform.bios_version.as_deref(), Some("1.2"));
+    assert_eq!(platform.sockets, ["CPU0", "CPU1"]);
+}
+
+#[test]
+fn invalid_smbios_table() {
+    assert_eq!(Platform::from_smbios_table(&[0x01, 0x02, 0x00]), None);
+}
+
+#[test]
+fn smbios_table_round_trip() {
+    let platform = platform();
+    let table = platform.to_smbios_table();
+    assert_eq!(Platform::from_smbios_table(&table), Some(platform));
+}
+
+#[test]
+fn entry_point() {
+    let mut entry_point = [0; 0x18];
+    entry_point[..5].copy_from_slice(b"_SM3_");
+    entry_point[0x0C..0x10].copy_from_slice(&0x1000u32.to_le_bytes());
+    entry_point[0x10..0x18].copy_from_slice(&0x7654_3210u64.to_le_bytes());
+    assert_eq!(table_location(&entry_point), Some((0x7654_3210, 0x1000)));
+
+    let mut entry_point = [0; 0x1F];
+    entry_point[..4].copy_from_slice(b"_SM_");
+    entry_point[0x16..0x18].copy_from_slice(&0x200u16.to_le_bytes());
+    entry_point[0x18..0x1C].copy_from_slice(&0xF0000u32.to_le_bytes());
+    assert_eq!(table_location(&entry_point), Some((0xF0000, 0x200)));
+
+    assert_eq!(table_location(b"BERT"), None);
+}
+
+#[test]
+fn crashlog_platform() {
+    let bert = std::fs::read("tests/samples/dummy.bert").unwrap();
+    let mut crashlog = CrashLog::from_slice(&bert).unwrap();
+    assert_eq!(crashlog.metadata.platform, None);
+    assert!(crashlog.decode_without_cm().get("platform").is_none());
+
+    crashlog.metadata.platform = Some(platform());
+
+    // The platform identity is only written when explicitly requested.
+    let exported = CrashLog::from_slice(&crashlog.export(ContainerType::Bert)).unwrap();
+    assert_eq!(exported.metadata.platform, None);
+
+    for container_type in [ContainerType::Bert, ContainerType::Cper] {
+        let data = crashlog.export_with_platform(container_type);
+        let exported = CrashLog::from_slice(&data).unwrap();
+        assert_eq!(exported.regions.len(), 2);
+        assert_eq!(exported.metadata.platform, Some(platform()));
+        let exported = CrashLogRef::from_slice(&data).unwrap();
+        assert_eq!(exported.metadata.platform, Some(platform()));
+    }
+
+    let root = crashlog.decode_without_cm();
+    let node = root.get_by_path("platform.bios_version").unwrap();
+    assert_eq!(node.description, "XYZ.0042");
+
+    #[cfg(feature = "serialize")]
+    {
+        let json = serde_json::to_value(&root).unwrap();
+        assert_eq!(
+            json["crashlog_data"]["platform"],
+            serde_json::json!({
+                "system_vendor": "Intel Corporation",
+                "product": "XYZ Reference Platform",
+                "serial": "0123456789",
+                "bios_version": "XYZ.0042",
+                "sockets": {"socket0": "CPU0", "socket1": "CPU1"},
+            })
+        );
+    }
+}
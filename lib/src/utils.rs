@@ -3,10 +3,49 @@
 
 #[cfg(not(feature = "std"))]
 use alloc::collections::BTreeMap;
+#[cfg(feature = "collateral_manager")]
+use core::ops::{Deref, DerefMut};
 #[cfg(feature = "std")]
 use std::collections::HashMap;
+#[cfg(all(feature = "collateral_manager", feature = "std"))]
+use std::sync::PoisonError;
 
 #[cfg(feature = "std")]
 pub type Map<K, T> = HashMap<K, T>;
 #[cfg(not(feature = "std"))]
 pub type Map<K, T> = BTreeMap<K, T>;
+
+/// Lock providing interior mutability to the data shared between threads.
+///
+/// The lock falls back to a [core::cell::RefCell] in `#![no_std]` environments, which are
+/// assumed to be single-threaded.
+#[cfg(feature = "collateral_manager")]
+#[derive(Default)]
+pub struct Lock<T> {
+    #[cfg(feature = "std")]
+    inner: std::sync::RwLock<T>,
+    #[cfg(not(feature = "std"))]
+    inner: core::cell::RefCell<T>,
+}
+
+#[cfg(all(feature = "collateral_manager", feature = "std"))]
+impl<T> Lock<T> {
+    pub fn read(&self) -> impl Deref<Target = T> + '_ {
+        self.inner.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn write(&self) -> impl DerefMut<Target = T> + '_ {
+        self.inner.write().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(all(feature = "collateral_manager", not(feature = "std")))]
+impl<T> Lock<T> {
+    pub fn read(&self) -> impl Deref<Target = T> + '_ {
+        self.inner.borrow()
+    }
+
+    pub fn write(&self) -> impl DerefMut<Target = T> + '_ {
+        self.inner.borrow_mut()
+    }
+}
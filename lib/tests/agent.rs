@@ -7,8 +7,8 @@ use intel_crashlog::prelude::*;
 fn invalid_recipe() {
     let data = std::fs::read("tests/samples/invalid_recipe.crashlog").unwrap();
     let crashlog = CrashLog::from_slice(&data).unwrap();
-    let mut cm = CollateralManager::embedded_tree().unwrap();
-    let nodes = crashlog.decode(&mut cm);
+    let cm = CollateralManager::embedded_tree().unwrap();
+    let nodes = crashlog.decode(&cm);
 
     let status = nodes.get_by_path("crashlog_agent.status").unwrap();
     assert_eq!(status.kind, NodeType::Field { value: 1 });
@@ -1,15 +1,19 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
-use intel_crashlog::collateral::PVSS;
+use intel_crashlog::collateral::{
+    CollateralTree, EmbeddedTree, FileSystemTree, ItemPath, LayeredTree, MemoryTree, PVSS,
+};
 use intel_crashlog::prelude::*;
+use intel_crashlog::record::Layout;
 use std::path::Path;
+use std::sync::Arc;
 
 const COLLATERAL_TREE_PATH: &str = "tests/collateral";
 
 #[test]
 fn get_with_pvss() {
-    let mut cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+    let cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
     let pvss = PVSS {
         product: "XYZ".into(),
         security: "green".into(),
@@ -28,7 +32,7 @@ fn target_info() {
 
 #[test]
 fn get_with_pvss_embedded() {
-    let mut cm = CollateralManager::embedded_tree().unwrap();
+    let cm = CollateralManager::embedded_tree().unwrap();
     let pvss = PVSS {
         product: "LNC".into(),
         security: "all".into(),
@@ -44,9 +48,28 @@ fn target_info_embedded() {
     assert_eq!(cm.target_info.get(&0x052).unwrap().product, "LNC");
 }
 
+#[test]
+fn decompress_embedded() {
+    let embedded = EmbeddedTree::new();
+    let fs = FileSystemTree::new(Path::new("collateral"));
+
+    for (pvss, path) in [
+        ("LNC/all/all/all", "target_info.json"),
+        ("LNC/all/all/green", "decode-defs/PCORE/1/layout_thread.csv"),
+        ("LNL/M/all/green", "decode-defs/MCA/all/layout.csv"),
+    ] {
+        let pvss: PVSS = pvss.parse().unwrap();
+        let path = ItemPath::from(path);
+        assert_eq!(
+            embedded.get(&pvss, &path).unwrap(),
+            fs.get(&pvss, &path).unwrap()
+        );
+    }
+}
+
 #[test]
 fn out_of_tree() {
-    let mut cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+    let cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
     let pvss = PVSS {
         product: "XYZ".into(),
         security: "green".into(),
@@ -58,3 +81,364 @@ fn out_of_tree() {
             .is_err()
     );
 }
+
+#[test]
+fn layout_embedded() {
+    let cm = CollateralManager::embedded_tree().unwrap();
+    let pvss = PVSS {
+        product: "LNC".into(),
+        ..PVSS::default()
+    };
+    let path = "decode-defs/PCORE/1/layout_thread.csv";
+
+    let csv = cm.get_item_with_pvss(pvss.clone(), path).unwrap();
+    let layout = cm.get_layout_with_pvss(pvss, path).unwrap();
+    assert_eq!(*layout, Layout::from_csv(&csv).unwrap());
+}
+
+#[test]
+fn layout_cache() {
+    let cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+    let pvss = PVSS {
+        product: "XYZ".into(),
+        ..PVSS::default()
+    };
+    let path = "decode-defs/MCA/1/layout.csv";
+
+    let first = cm.get_layout_with_pvss(pvss.clone(), path).unwrap();
+    let second = cm.get_layout_with_pvss(pvss, path).unwrap();
+    assert!(Arc::ptr_eq(&first, &second));
+}
+
+#[test]
+fn send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<CollateralManager<EmbeddedTree>>();
+    assert_send_sync::<CollateralManager<FileSystemTree>>();
+    assert_send_sync::<CollateralManager<LayeredTree>>();
+}
+
+#[test]
+fn shared_between_threads() {
+    let cm = CollateralManager::embedded_tree().unwrap();
+    let data = std::fs::read("tests/samples/three_strike_timeout_box.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    let expected = crashlog.decode(&cm);
+
+    std::thread::scope(|s| {
+        let threads: Vec<_> = (0..4).map(|_| s.spawn(|| crashlog.decode(&cm))).collect();
+        for thread in threads {
+            assert_eq!(thread.join().unwrap(), expected);
+        }
+    });
+}
+
+/// Collateral tree redefining the target info of the XYZ product
+struct OverrideTree;
+
+impl CollateralTree for OverrideTree {
+    fn get(&self, pvss: &PVSS, path: &ItemPath) -> Result<Vec<u8>, Error> {
+        if *pvss == Self::pvss() && *path == ItemPath::new(["target_info.json"]) {
+            return Ok(
+                br#"{"product": "XYZ", "product_id": "0x07a", "variant": "override"}"#.into(),
+            );
+        }
+        Err(Error::MissingCollateral(pvss.clone(), path.clone()))
+    }
+
+    fn search(&self, path: &ItemPath) -> Result<Vec<PVSS>, Error> {
+        Ok(self
+            .get(&Self::pvss(), path)
+            .map(|_| vec![Self::pvss()])
+            .unwrap_or_default())
+    }
+
+    fn list(&self, path: &ItemPath) -> Result<Vec<(PVSS, ItemPath)>, Error> {
+        let item = ItemPath::new(["target_info.json"]);
+        Ok(if item.starts_with(path) {
+            vec![(Self::pvss(), item)]
+        } else {
+            Vec::new()
+        })
+    }
+}
+
+impl OverrideTree {
+    fn pvss() -> PVSS {
+        PVSS {
+            product: "XYZ".into(),
+            ..PVSS::default()
+        }
+    }
+}
+
+#[test]
+fn layered_tree() {
+    let mut tree = LayeredTree::default();
+    tree.push(0, EmbeddedTree::new());
+    tree.push(1, FileSystemTree::new(Path::new(COLLATERAL_TREE_PATH)));
+    assert_eq!(tree.len(), 2);
+
+    let cm = CollateralManager::layered_tree(tree).unwrap();
+    assert_eq!(cm.target_info.get(&0x052).unwrap().product, "LNC");
+    assert_eq!(cm.target_info.get(&0x07A).unwrap().product, "XYZ");
+
+    let pvss = PVSS {
+        product: "XYZ".into(),
+        security: "green".into(),
+        ..PVSS::default()
+    };
+    assert!(
+        cm.get_layout_with_pvss(pvss, "decode-defs/MCA/1/layout.csv")
+            .is_ok()
+    );
+
+    let pvss = PVSS {
+        product: "LNC".into(),
+        ..PVSS::default()
+    };
+    let path = "decode-defs/PCORE/1/layout_thread.csv";
+    let layout = cm.get_layout_with_pvss(pvss.clone(), path).unwrap();
+    assert_eq!(
+        *layout,
+        *CollateralManager::embedded_tree()
+            .unwrap()
+            .get_layout_with_pvss(pvss, path)
+            .unwrap()
+    );
+}
+
+#[test]
+fn layered_tree_priority() {
+    let target_info = ItemPath::new(["target_info.json"]);
+
+    for (overlay, variant) in [(1, "override"), (-1, "all")] {
+        let mut tree = LayeredTree::default();
+        tree.push(0, EmbeddedTree::new());
+        tree.push(overlay, OverrideTree);
+
+        let search = tree.search(&target_info).unwrap();
+        assert_eq!(
+            search
+                .iter()
+                .filter(|pvss| **pvss == OverrideTree::pvss())
+                .count(),
+            1
+        );
+
+        let cm = CollateralManager::layered_tree(tree).unwrap();
+        assert_eq!(cm.target_info.get(&0x07A).unwrap().variant, variant);
+    }
+}
+
+/// Collateral tree that cannot be accessed
+struct FailingTree;
+
+impl CollateralTree for FailingTree {
+    fn get(&self, _pvss: &PVSS, _path: &ItemPath) -> Result<Vec<u8>, Error> {
+        Err(Error::InternalError)
+    }
+
+    fn search(&self, _path: &ItemPath) -> Result<Vec<PVSS>, Error> {
+        Err(Error::InternalError)
+    }
+
+    fn list(&self, _path: &ItemPath) -> Result<Vec<(PVSS, ItemPath)>, Error> {
+        Err(Error::InternalError)
+    }
+}
+
+#[test]
+fn layered_tree_failing_layer() {
+    let target_info = ItemPath::new(["target_info.json"]);
+
+    let mut tree = LayeredTree::default();
+    tree.push(1, FailingTree);
+    assert!(tree.search(&target_info).is_err());
+
+    tree.push(0, FileSystemTree::new(Path::new(COLLATERAL_TREE_PATH)));
+    assert!(!tree.search(&target_info).unwrap().is_empty());
+
+    let cm = CollateralManager::layered_tree(tree).unwrap();
+    assert_eq!(cm.target_info.get(&0x07A).unwrap().product, "XYZ");
+}
+
+#[test]
+fn memory_tree() {
+    let fs = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+    let pvss: PVSS = "XYZ/all/all/green".parse().unwrap();
+    let base = Path::new(COLLATERAL_TREE_PATH).join("XYZ/all/all/green/crashlog");
+
+    let mut tree = MemoryTree::new();
+    for path in ["target_info.json", "decode-defs/MCA/1/layout.csv"] {
+        let content = std::fs::read(base.join(path)).unwrap();
+        assert!(tree.insert(pvss.clone(), path, content).is_none());
+    }
+
+    let mut cm = CollateralManager::memory_tree(tree).unwrap();
+    assert_eq!(cm.target_info.get(&0x07A).unwrap().product, "XYZ");
+
+    let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    assert_eq!(crashlog.decode(&cm), crashlog.decode(&fs));
+
+    let path = ItemPath::new(["decode-defs", "MCA", "1", "layout.csv"]);
+    let layout = "name;offset;size\nmca;0;64\nmca.foo;32;8";
+    cm.update_tree(|tree| {
+        assert!(tree.insert(pvss.clone(), path.clone(), layout).is_some());
+    })
+    .unwrap();
+    assert_eq!(
+        *cm.get_item_with_pvss(pvss.clone(), path.clone()).unwrap(),
+        *layout.as_bytes()
+    );
+    let root = crashlog.decode(&cm);
+    assert!(root.get_by_path("mca.foo").is_some());
+    assert!(root.get_by_path("mca.hdr").is_none());
+
+    cm.update_tree(|tree| {
+        assert!(tree.remove(&pvss, &path).is_some());
+        assert!(tree.remove(&pvss, &path).is_none());
+    })
+    .unwrap();
+    assert!(cm.tree().get(&pvss, &path).is_err());
+    assert!(!cm.tree().is_empty());
+    assert!(crashlog.decode(&cm).get_by_path("mca.foo").is_none());
+
+    for pvss in ["XYZ/all/all", "XYZ/all/all/all/all"] {
+        assert!(matches!(
+            pvss.parse::<PVSS>(),
+            Err(Error::InvalidPVSS(invalid)) if invalid == pvss
+        ));
+    }
+}
+
+#[test]
+fn inventory() {
+    let cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+    let inventory = cm.inventory().unwrap();
+
+    let pvss: Vec<String> = inventory
+        .iter()
+        .map(|entry| entry.pvss.to_string())
+        .collect();
+    assert_eq!(pvss, ["ABC/all/all/green", "XYZ/all/all/green"]);
+
+    let abc = &inventory[0];
+    assert_eq!(abc.product_id, None);
+    assert!(abc.target_info);
+    assert!(abc.decode_definitions.is_empty());
+
+    let xyz = &inventory[1];
+    assert_eq!(xyz.product_id, Some(0x7a));
+    assert!(xyz.target_info);
+    let decode_defs: Vec<String> = xyz
+        .decode_definitions
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        decode_defs,
+        [
+            "CRASHLOG_AGENT revision 1 (io)",
+            "MCA revision 1",
+            "MCA revision 2 (io)",
+            "MCA revision all"
+        ]
+    );
+}
+
+#[test]
+fn inventory_memory_tree() {
+    let green: PVSS = "XYZ/all/all/green".parse().unwrap();
+    let red: PVSS = "XYZ/all/all/red".parse().unwrap();
+    let layout = "name;offset;size\nmca;0;64";
+
+    let mut tree = MemoryTree::new();
+    tree.insert(
+        green.clone(),
+        "target_info.json",
+        r#"{"product": "XYZ", "product_id": "0x7a", "die_id": {"0": "io0"}}"#,
+    );
+    for path in [
+        "decode-defs/MCA/all/layout.csv",
+        "decode-defs/MCA/300/layout.csv",
+        "decode-defs/MCA/io/2/layout.csv",
+        "decode-defs/MCA/1/layout.csv",
+        "decode-defs/MCA/1/README.md",
+    ] {
+        tree.insert(green.clone(), path, layout);
+    }
+    tree.insert(red.clone(), "decode-defs/MCA/1/layout.csv", layout);
+
+    let cm = CollateralManager::memory_tree(tree).unwrap();
+    let inventory = cm.inventory().unwrap();
+    assert_eq!(inventory.len(), 2);
+
+    let entry = &inventory[0];
+    assert_eq!(entry.pvss, green);
+    assert_eq!(entry.product_id, Some(0x7a));
+    assert!(entry.target_info);
+    let decode_defs: Vec<String> = entry
+        .decode_definitions
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        decode_defs,
+        [
+            "MCA revision 1",
+            "MCA revision 2 (io)",
+            "MCA revision 300",
+            "MCA revision all"
+        ]
+    );
+    assert_eq!(entry.decode_definitions[1].die.as_deref(), Some("io"));
+    assert_eq!(entry.decode_definitions[3].revision, "all");
+
+    let entry = &inventory[1];
+    assert_eq!(entry.pvss, red);
+    assert_eq!(entry.product_id, Some(0x7a));
+    assert!(!entry.target_info);
+    assert_eq!(entry.decode_definitions.len(), 1);
+    assert_eq!(entry.decode_definitions[0].to_string(), "MCA revision 1");
+}
+
+#[cfg(feature = "archive_collateral_tree")]
+#[test]
+fn archive_tree() {
+    use intel_crashlog::collateral::ArchiveTree;
+
+    let fs = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+    let pvss = PVSS {
+        product: "XYZ".into(),
+        ..PVSS::default()
+    };
+    let path = "decode-defs/MCA/1/layout.csv";
+
+    for archive in [
+        "tests/samples/collateral.zip",
+        "tests/samples/collateral.tar.gz",
+    ] {
+        let cm = CollateralManager::archive_tree(Path::new(archive)).unwrap();
+        assert_eq!(cm.target_info.len(), 1, "{archive}");
+        assert_eq!(cm.target_info.get(&0x07A).unwrap().product, "XYZ");
+        assert_eq!(
+            cm.get_item_with_pvss(pvss.clone(), path).unwrap(),
+            fs.get_item_with_pvss(pvss.clone(), path).unwrap(),
+            "{archive}"
+        );
+        assert!(cm.get_item_with_pvss(pvss.clone(), "missing.csv").is_err());
+    }
+
+    let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    let cm = CollateralManager::new(
+        ArchiveTree::from_slice(&std::fs::read("tests/samples/collateral.tar.gz").unwrap())
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(crashlog.decode(&cm), crashlog.decode(&fs));
+
+    assert!(ArchiveTree::from_slice(b"PK\x03\x04invalid").is_err());
+}
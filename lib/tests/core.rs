@@ -7,8 +7,8 @@ use intel_crashlog::prelude::*;
 fn lnc_three_strike_timeout() {
     let data = std::fs::read("tests/samples/three_strike_timeout.crashlog").unwrap();
     let crashlog = CrashLog::from_slice(&data).unwrap();
-    let mut cm = CollateralManager::embedded_tree().unwrap();
-    let nodes = crashlog.decode(&mut cm);
+    let cm = CollateralManager::embedded_tree().unwrap();
+    let nodes = crashlog.decode(&cm);
 
     let status = nodes
         .get_by_path("core0.thread.arch_state.mca.bank3.status")
@@ -1,6 +1,7 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
+use intel_crashlog::node::PathFilter;
 use intel_crashlog::prelude::*;
 
 #[test]
@@ -16,8 +17,8 @@ fn crashlog_decode() {
     let bert = std::fs::read("tests/samples/dummy.bert").unwrap();
     let crashlog = CrashLog::from_slice(&bert).unwrap();
 
-    let mut cm = CollateralManager::embedded_tree().unwrap();
-    let root = crashlog.decode(&mut cm);
+    let cm = CollateralManager::embedded_tree().unwrap();
+    let root = crashlog.decode(&cm);
     let mut children = root.children();
 
     assert_eq!(children.next().unwrap().name, "mca");
@@ -42,8 +43,8 @@ fn core_box_header_type6() {
     let data = std::fs::read("tests/samples/three_strike_timeout_box.crashlog").unwrap();
     let crashlog = CrashLog::from_slice(&data).unwrap();
 
-    let mut cm = CollateralManager::embedded_tree().unwrap();
-    let root = crashlog.decode(&mut cm);
+    let cm = CollateralManager::embedded_tree().unwrap();
+    let root = crashlog.decode(&cm);
 
     let entry0 = root
         .get_by_path("processors.cpu0.die8.core0.sq.entry0")
@@ -72,3 +73,121 @@ fn core_box_header_type6() {
         }
     );
 }
+
+#[test]
+fn crashlog_ref() {
+    let cm = CollateralManager::embedded_tree().unwrap();
+
+    for sample in [
+        "tests/samples/dummy.bert",
+        "tests/samples/cper.whea",
+        "tests/samples/three_strike_timeout_box.crashlog",
+    ] {
+        let data = std::fs::read(sample).unwrap();
+        let borrowed = CrashLogRef::from_slice(&data).unwrap();
+        let owned = CrashLog::from_slice(&data).unwrap();
+
+        assert_eq!(borrowed.regions.len(), owned.regions.len(), "{sample}");
+        assert_eq!(borrowed.metadata.source, owned.metadata.source, "{sample}");
+        assert_eq!(
+            serde_json::to_value(borrowed.decode(&cm)).unwrap(),
+            serde_json::to_value(owned.decode(&cm)).unwrap(),
+            "{sample}"
+        );
+
+        let range = data.as_ptr_range();
+        for record in borrowed
+            .regions
+            .iter()
+            .flat_map(|region| region.records.iter())
+        {
+            assert!(range.contains(&record.data.as_ptr()), "{sample}");
+        }
+
+        let converted = CrashLog::from(borrowed);
+        assert_eq!(converted.regions.len(), owned.regions.len());
+    }
+}
+
+#[test]
+fn decode_paths() {
+    let cm = CollateralManager::embedded_tree().unwrap();
+
+    for (sample, paths) in [
+        (
+            "tests/samples/three_strike_timeout_box.crashlog",
+            vec![
+                "processors.cpu0.die*.core*.thread.arch_state.lip",
+                "processors.cpu0.die8.core0.sq.entry0",
+                "processors.*.*.box.hdr",
+            ],
+        ),
+        ("tests/samples/dummy.bert", vec!["*.hdr.version", "source"]),
+        (
+            "tests/samples/cper.whea",
+            vec!["pmc*.hdr", "source.region1"],
+        ),
+    ] {
+        let data = std::fs::read(sample).unwrap();
+        let crashlog = CrashLog::from_slice(&data).unwrap();
+
+        let mut expected = crashlog.decode(&cm);
+        expected.retain(&PathFilter::new(&paths));
+        assert_ne!(expected, Node::root(), "{sample}");
+
+        let root = crashlog.decode_paths(&cm, &paths);
+        assert_eq!(root, expected, "{sample}");
+
+        let borrowed = CrashLogRef::from_slice(&data).unwrap();
+        let root = borrowed.decode_paths(&cm, &paths);
+        assert_eq!(
+            root.get_by_path("processors"),
+            expected.get_by_path("processors"),
+            "{sample}"
+        );
+    }
+}
+
+#[test]
+fn decode_to_writer() {
+    let cm = CollateralManager::embedded_tree().unwrap();
+
+    for sample in [
+        "tests/samples/dummy.bert",
+        "tests/samples/cper.whea",
+        "tests/samples/cper_sections.bert",
+        "tests/samples/dummy_mca_rev1_box.crashlog",
+        "tests/samples/three_strike_timeout_box.crashlog",
+    ] {
+        let data = std::fs::read(sample).unwrap();
+        let crashlog = CrashLog::from_slice(&data).unwrap();
+
+        let mut json = Vec::new();
+        crashlog.decode_to_writer(&cm, &mut json).unwrap();
+        let expected = serde_json::to_vec_pretty(&crashlog.decode(&cm)).unwrap();
+        assert_eq!(
+            String::from_utf8(json).unwrap(),
+            String::from_utf8(expected).unwrap(),
+            "{sample}"
+        );
+    }
+
+    let mut data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    data.extend_from_within(..);
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    let root = crashlog.decode(&cm);
+    assert!(root.get("mca0").is_some());
+    let mut json = Vec::new();
+    crashlog.decode_to_writer(&cm, &mut json).unwrap();
+    assert_eq!(json, serde_json::to_vec_pretty(&root).unwrap());
+
+    let data = std::fs::read("tests/samples/dummy.bert").unwrap();
+    let crashlogs = [
+        CrashLog::from_slice(&data).unwrap(),
+        CrashLog::from_slice(&data).unwrap(),
+    ];
+    let mut json = Vec::new();
+    CrashLog::decode_all_to_writer(&crashlogs, &cm, &mut json).unwrap();
+    let nodes: Vec<Node> = crashlogs.iter().map(|c| c.decode(&cm)).collect();
+    assert_eq!(json, serde_json::to_vec_pretty(&nodes).unwrap());
+}
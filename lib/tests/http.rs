@@ -0,0 +1,177 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+#![cfg(feature = "http_collateral_tree")]
+
+use intel_crashlog::collateral::{EmbeddedTree, HttpTree, LayeredTree, PVSS};
+use intel_crashlog::prelude::*;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+const COLLATERAL_TREE_PATH: &str = "tests/collateral";
+
+/// Requests received by the server and the status of the responses
+type Log = Arc<Mutex<Vec<(String, u16)>>>;
+
+fn list(dir: &Path, prefix: &str, index: &mut String) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let entry = entry.unwrap();
+        let name = format!("{prefix}{}", entry.file_name().to_str().unwrap());
+        if entry.path().is_dir() {
+            list(&entry.path(), &format!("{name}/"), index);
+        } else {
+            index.push_str(&name);
+            index.push('\n');
+        }
+    }
+}
+
+/// Serves the test collateral tree over HTTP on a local port, one request per connection.
+fn serve() -> (String, Log) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let log = Log::default();
+
+    let server_log = log.clone();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(&stream);
+            let mut path = String::new();
+            let mut if_none_match = None;
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+                if let Some(target) = line.strip_prefix("GET /") {
+                    path = target.split(' ').next().unwrap().to_string();
+                } else if let Some((name, value)) = line.split_once(':')
+                    && name.eq_ignore_ascii_case("if-none-match")
+                {
+                    if_none_match = Some(value.trim().to_string());
+                }
+                line.clear();
+            }
+
+            let content = if path == "index.txt" {
+                let mut index = String::new();
+                list(Path::new(COLLATERAL_TREE_PATH), "", &mut index);
+                Some(index.into_bytes())
+            } else {
+                std::fs::read(Path::new(COLLATERAL_TREE_PATH).join(&path)).ok()
+            };
+
+            let response = match content {
+                Some(content) => {
+                    let mut hasher = DefaultHasher::new();
+                    content.hash(&mut hasher);
+                    let etag = format!("\"{:x}\"", hasher.finish());
+                    if if_none_match.as_deref() == Some(etag.as_str()) {
+                        (304, format!("ETag: {etag}\r\n"), Vec::new())
+                    } else {
+                        (200, format!("ETag: {etag}\r\n"), content)
+                    }
+                }
+                None => (404, String::new(), Vec::new()),
+            };
+
+            server_log.lock().unwrap().push((path, response.0));
+            write!(
+                stream,
+                "HTTP/1.1 {} X\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n",
+                response.0,
+                response.1,
+                response.2.len()
+            )
+            .unwrap();
+            stream.write_all(&response.2).unwrap();
+        }
+    });
+
+    (url, log)
+}
+
+fn statuses(log: &Log, path: &str) -> Vec<u16> {
+    log.lock()
+        .unwrap()
+        .iter()
+        .filter(|(p, _)| p == path)
+        .map(|(_, status)| *status)
+        .collect()
+}
+
+#[test]
+fn http_tree() {
+    let cache: PathBuf = std::env::temp_dir().join(format!("iclg-http-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&cache);
+
+    let (url, log) = serve();
+    let fs = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+    let pvss = PVSS {
+        product: "XYZ".into(),
+        ..PVSS::default()
+    };
+    let item = "decode-defs/MCA/1/layout.csv";
+    let path = "XYZ/all/all/green/crashlog/decode-defs/MCA/1/layout.csv";
+
+    let cm = CollateralManager::http_tree(&url, &cache).unwrap();
+    assert_eq!(cm.target_info.get(&0x07A).unwrap().product, "XYZ");
+    assert_eq!(
+        cm.get_item_with_pvss(pvss.clone(), item).unwrap(),
+        fs.get_item_with_pvss(pvss.clone(), item).unwrap()
+    );
+    assert!(cm.get_item_with_pvss(pvss.clone(), "missing.csv").is_err());
+    assert_eq!(statuses(&log, path), [200]);
+    assert!(statuses(&log, "XYZ/all/all/green/crashlog/missing.csv").is_empty());
+    assert!(cache.join(path).exists());
+
+    let cm = CollateralManager::http_tree(&url, &cache).unwrap();
+    assert!(cm.get_item_with_pvss(pvss.clone(), item).is_ok());
+    assert_eq!(statuses(&log, path), [200, 304]);
+    assert_eq!(statuses(&log, "index.txt"), [200, 304]);
+
+    let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    for tree in [
+        HttpTree::new("http://127.0.0.1:1", &cache),
+        HttpTree::new(&url, &cache).offline(true),
+    ] {
+        let cm = CollateralManager::new(tree).unwrap();
+        assert_eq!(
+            cm.get_item_with_pvss(pvss.clone(), item).unwrap(),
+            fs.get_item_with_pvss(pvss.clone(), item).unwrap()
+        );
+        assert_eq!(crashlog.decode(&cm), crashlog.decode(&fs));
+    }
+    assert_eq!(statuses(&log, "index.txt").len(), 2);
+
+    std::fs::remove_dir_all(&cache).unwrap();
+}
+
+#[test]
+fn http_tree_unavailable() {
+    let cache: PathBuf =
+        std::env::temp_dir().join(format!("iclg-http-empty-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&cache);
+
+    let embedded = CollateralManager::embedded_tree().unwrap();
+    let data = std::fs::read("tests/samples/three_strike_timeout_box.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+
+    for tree in [
+        HttpTree::new("http://127.0.0.1:1", &cache),
+        HttpTree::new("http://127.0.0.1:1", &cache).offline(true),
+    ] {
+        let mut layers = LayeredTree::default();
+        layers.push(0, EmbeddedTree::new());
+        layers.push(1, tree);
+
+        let cm = CollateralManager::layered_tree(layers).unwrap();
+        assert_eq!(cm.target_info.len(), embedded.target_info.len());
+        assert_eq!(crashlog.decode(&cm), crashlog.decode(&embedded));
+    }
+
+    let cm = CollateralManager::http_tree("http://127.0.0.1:1", &cache).unwrap();
+    assert!(cm.target_info.is_empty());
+    assert!(!cache.exists());
+}
@@ -1,6 +1,7 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
+use intel_crashlog::node::PathFilter;
 use intel_crashlog::prelude::*;
 use serde_json::json;
 
@@ -116,3 +117,28 @@ fn merge_section() {
     assert_eq!(root0.get("foo").unwrap().kind, NodeType::Section);
     assert!(root0.get("foo0").is_none());
 }
+
+#[test]
+fn retain() {
+    let mut root = Node::root();
+    root.create_hierarchy("core0.thread.arch_state.lip");
+    root.create_hierarchy("core0.thread.hdr.whoami");
+    root.create_hierarchy("core12.thread.arch_state.lip");
+    root.create_hierarchy("mca.status");
+    root.create_hierarchy("mca0.status");
+
+    let mut filtered = Node::root();
+    filtered.create_hierarchy("core0.thread.arch_state.lip");
+    filtered.create_hierarchy("core12.thread.arch_state.lip");
+    filtered.create_hierarchy("mca0.status");
+
+    root.retain(&PathFilter::new([
+        "core*.thread.arch_state",
+        "mca0",
+        "missing.path",
+    ]));
+    assert_eq!(root, filtered);
+
+    root.retain(&PathFilter::new(Vec::<&str>::new()));
+    assert_eq!(root, Node::root());
+}
@@ -4,6 +4,7 @@
 
 use intel_crashlog::header::{RecordSize, Version};
 use intel_crashlog::prelude::*;
+use intel_crashlog::record::Layout;
 use std::assert_matches::assert_matches;
 use std::fs;
 use std::path::Path;
@@ -77,9 +78,36 @@ foo.ddd.eee;32;8;;0
     assert_eq!(field.kind, NodeType::Field { value: 0x86 });
 }
 
+#[test]
+fn decode_with_layout() {
+    let record = Record {
+        header: Header::default(),
+        data: vec![0x80, 0x81, 0x82, 0x83],
+        ..Default::default()
+    };
+
+    let csv = "name;offset;size;description;bitfield
+foo;0;32;;0
+.aaa;8;8;first;0
+..bbb;16;8;;0";
+
+    let layout = Layout::from_csv(csv.as_bytes()).unwrap();
+    assert_eq!(layout.fields.len(), 3);
+    assert_eq!(layout.fields[1].path, ["foo", "aaa"]);
+    assert_eq!(layout.fields[1].description, "first");
+    assert!(layout.fields[0].record);
+    assert!(!layout.fields[2].record);
+    assert_eq!(layout.fields[2].path, ["foo", "bbb"]);
+
+    let root = record.decode_with_layout(&layout, 1);
+    assert_eq!(root, record.decode_with_csv(csv.as_bytes(), 1).unwrap());
+    let field = root.get_by_path("foo.aaa").unwrap();
+    assert_eq!(field.kind, NodeType::Field { value: 0x82 });
+}
+
 #[test]
 fn decode() {
-    let mut cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+    let cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
 
     let data = fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
     let header = Header::from_slice(&data).unwrap().unwrap();
@@ -89,7 +117,7 @@ fn decode() {
         ..Default::default()
     };
 
-    let root = record.decode(&mut cm);
+    let root = record.decode(&cm);
     let version = root.get_by_path("mca.hdr.version.revision").unwrap();
     assert_eq!(version.kind, NodeType::Field { value: 1 });
 }
@@ -114,8 +142,9 @@ fn decode_generic() {
         ..Default::default()
     };
 
-    let mut cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
-    let root = record.decode(&mut cm);
+    let cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+    assert!(record.has_decode_definitions(&cm));
+    let root = record.decode(&cm);
     let foo = root.get_by_path("mca.foo").unwrap();
     assert_eq!(foo.kind, NodeType::Field { value: 0x42 });
 }
@@ -140,8 +169,9 @@ fn decode_missing_decode_defs() {
         ..Default::default()
     };
 
-    let mut cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
-    let root = record.decode(&mut cm);
+    let cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+    assert!(!record.has_decode_definitions(&cm));
+    let root = record.decode(&cm);
 
     let revision = root.get_by_path("mca.hdr.version.revision").unwrap();
     assert_eq!(revision.kind, NodeType::Field { value: 42 });
@@ -152,7 +182,7 @@ fn decode_missing_decode_defs() {
 
 #[test]
 fn header_type6_decode() {
-    let mut cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+    let cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
 
     let data = fs::read("tests/samples/dummy_mca_rev2.crashlog").unwrap();
     let header = Header::from_slice(&data).unwrap().unwrap();
@@ -162,7 +192,7 @@ fn header_type6_decode() {
         ..Default::default()
     };
 
-    let root = record.decode(&mut cm);
+    let root = record.decode(&cm);
     let version = root
         .get_by_path("processors.cpu0.io1.mca.hdr.version.revision")
         .unwrap();
@@ -199,7 +229,7 @@ foo;0;64;;0
 foo.bar;=2+2;8;;0";
     assert_matches!(
         record.decode_with_csv(csv.as_bytes(), 0),
-        Err(Error::ParseIntError(_))
+        Err(Error::InvalidDecodeDefinition(3, _))
     );
 
     let csv = "fullname;size;offset
@@ -225,12 +255,12 @@ foo.bar;8
 
 #[test]
 fn box_header_type6() {
-    let mut cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+    let cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
     let data = fs::read("tests/samples/dummy_mca_rev1_box.crashlog").unwrap();
 
     let crashlog = CrashLog::from_slice(&data).unwrap();
 
-    let root = crashlog.decode(&mut cm);
+    let root = crashlog.decode(&cm);
 
     let header_type = root
         .get_by_path("processors.cpu1.io0.mca.hdr.version.header_type")